@@ -0,0 +1,98 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[path = "src/fnv.rs"]
+mod fnv;
+
+use fnv::{FNV_OFFSET, fnv1a};
+
+// Hashes the sources of every day crate. The cache of the runner uses the hash as the version of
+// the solver (see src/cache.rs), so the cached answers of a day are dropped whenever it changes.
+// The days also depend on aoc_common, on their adapters in src/days.rs and on the locked versions
+// of their dependencies, those files are part of the hash of every day.
+
+fn collect_files(directory: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+// Files which every solver depends on, relative to the root of the workspace
+fn shared_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = vec![root.join("Cargo.lock"), root.join("aoc/src/days.rs")];
+    collect_files(&root.join("aoc_common/src"), &mut files);
+    files
+}
+
+fn hash_files(mut hash: u64, base: &Path, files: &mut [PathBuf]) -> u64 {
+    files.sort();
+    for file in files.iter() {
+        let relative = file.strip_prefix(base).unwrap_or(file);
+        hash = fnv1a(hash, relative.to_string_lossy().as_bytes());
+        hash = fnv1a(hash, &fs::read(file).unwrap_or_default());
+    }
+    hash
+}
+
+fn solver_version(crate_dir: &Path, shared_hash: u64) -> u64 {
+    let mut files = vec![crate_dir.join("Cargo.toml")];
+    collect_files(&crate_dir.join("src"), &mut files);
+    hash_files(shared_hash, crate_dir, &mut files)
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let root = manifest_dir.parent().unwrap_or(&manifest_dir);
+    // A new day is added to the workspace manifest
+    println!(
+        "cargo:rerun-if-changed={}",
+        root.join("Cargo.toml").display()
+    );
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/fnv.rs");
+    println!("cargo:rerun-if-changed=src/days.rs");
+    println!(
+        "cargo:rerun-if-changed={}",
+        root.join("Cargo.lock").display()
+    );
+    println!(
+        "cargo:rerun-if-changed={}",
+        root.join("aoc_common/src").display()
+    );
+    let shared_hash = hash_files(FNV_OFFSET, root, &mut shared_files(root));
+
+    let mut versions = Vec::new();
+    for day in 1..=25 {
+        let crate_dir = root.join(format!("day{:02}", day));
+        if !crate_dir.join("Cargo.toml").exists() {
+            versions.push(String::from("None"));
+            continue;
+        }
+        println!(
+            "cargo:rerun-if-changed={}",
+            crate_dir.join("Cargo.toml").display()
+        );
+        println!("cargo:rerun-if-changed={}", crate_dir.join("src").display());
+        versions.push(format!(
+            "Some({:#018x})",
+            solver_version(&crate_dir, shared_hash)
+        ));
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let generated = format!(
+        "// Generated by build.rs, the hash of the sources of every day\n\
+         pub static SOLVER_VERSIONS: [Option<u64>; 25] = [{}];\n",
+        versions.join(", ")
+    );
+    fs::write(out_dir.join("solver_versions.rs"), generated).unwrap();
+}
@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::solution::Part;
+
+// Accepted answers of one day. They are stored as strings, so big answers do not depend on
+// the integer types of TOML and every day is compared the same way as the runner prints it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+// Content of answers.toml, one table per day:
+//
+// [day01]
+// part1 = "997"
+// part2 = "5978"
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+impl Answers {
+    // A missing file is the same as a file without any answers, so the first --record creates it
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let text = fs::read_to_string(path)
+            .map_err(|error| format!("Cannot read {}: {}", path.display(), error))?;
+        Self::parse(&text).map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        toml::from_str(text).map_err(|error| error.message().to_string())
+    }
+
+    // The comment block at the top of the file is kept, the tables are written anew
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let header = fs::read_to_string(path)
+            .map(|text| leading_comments(&text))
+            .unwrap_or_default();
+        let text = toml::to_string(self).map_err(|error| error.to_string())?;
+        fs::write(path, header + &text)
+            .map_err(|error| format!("Cannot write {}: {}", path.display(), error))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        let answers = self.days.get(&day_key(day))?;
+        match part {
+            Part::One => answers.part1.as_deref(),
+            Part::Two => answers.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, day: u8, part: Part, answer: &str) {
+        let answers = self.days.entry(day_key(day)).or_default();
+        let slot = match part {
+            Part::One => &mut answers.part1,
+            Part::Two => &mut answers.part2,
+        };
+        *slot = Some(String::from(answer));
+    }
+}
+
+fn leading_comments(text: &str) -> String {
+    text.lines()
+        .take_while(|line| line.trim().is_empty() || line.trim_start().starts_with('#'))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Error,
+}
+
+impl Status {
+    pub fn check(expected: Option<&str>, actual: Option<&str>) -> Status {
+        match (expected, actual) {
+            (_, None) => Status::Error,
+            (None, Some(_)) => Status::Missing,
+            (Some(expected), Some(actual)) if expected == actual => Status::Pass,
+            (Some(_), Some(_)) => Status::Fail,
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::Error => "ERROR",
+        };
+        // Padding is passed on, so the status can be aligned in the table
+        f.pad(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn check_answers_file() {
+        let mut answers =
+            Answers::parse("[day01]\npart1 = \"997\"\n\n[day12]\npart1 = \"557\"\n").unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("997"));
+        assert_eq!(answers.get(1, Part::Two), None);
+        assert_eq!(answers.get(2, Part::One), None);
+
+        answers.set(1, Part::Two, "5978");
+        let saved = toml::to_string(&answers).unwrap();
+        assert_eq!(Answers::parse(&saved).unwrap(), answers);
+        assert!(Answers::parse("[day01]\npart1 = 997\n").is_err());
+    }
+
+    #[test]
+    fn check_save_keeps_header() {
+        let path = env::temp_dir().join(format!("aoc-answers-test-{}.toml", process::id()));
+        fs::write(&path, "# Accepted answers\n\n[day01]\npart1 = \"997\"\n").unwrap();
+        let mut answers = Answers::load(&path).unwrap();
+        answers.set(1, Part::Two, "5978");
+        answers.save(&path).unwrap();
+        let saved = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(saved.starts_with("# Accepted answers\n\n[day01]\n"));
+        assert_eq!(Answers::parse(&saved).unwrap(), answers);
+        assert_eq!(leading_comments("[day01]\n# not a header\n"), "");
+    }
+
+    #[test]
+    fn check_status() {
+        assert_eq!(Status::check(Some("997"), Some("997")), Status::Pass);
+        assert_eq!(Status::check(Some("997"), Some("998")), Status::Fail);
+        assert_eq!(Status::check(None, Some("997")), Status::Missing);
+        assert_eq!(Status::check(Some("997"), None), Status::Error);
+    }
+}
@@ -0,0 +1,201 @@
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::allocations::AllocationCount;
+use crate::solution::{Part, Solution, SolveError};
+
+// Timing of one part over all the runs. Times are in nanoseconds, the allocations are
+// counted during a single run, every run of a part allocates the same.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: u8,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+// Content of the JSON report, the same format is read back as the baseline
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Report, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|error| format!("Cannot read {}: {}", path.display(), error))?;
+        serde_json::from_str(&text)
+            .map_err(|error| format!("{} is not a benchmark report: {}", path.display(), error))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = serde_json::to_string_pretty(self).map_err(|error| error.to_string())?;
+        fs::write(path, text + "\n")
+            .map_err(|error| format!("Cannot write {}: {}", path.display(), error))
+    }
+
+    pub fn find(&self, day: u8, part: u8) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|measurement| measurement.day == day && measurement.part == part)
+    }
+}
+
+// Part which got slower than the threshold allows when compared to the baseline
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub part: u8,
+    pub baseline_ns: u64,
+    pub median_ns: u64,
+}
+
+impl Regression {
+    pub fn slowdown_percent(&self) -> f64 {
+        (self.median_ns as f64 / self.baseline_ns as f64 - 1.0) * 100.0
+    }
+}
+
+// Runs the part once to warm up the caches and count the allocations, then `runs` more times
+// to measure the wall time. A part which fails is not measured at all.
+pub fn measure(
+    solution: &dyn Solution,
+    part: Part,
+    input: &str,
+    runs: usize,
+) -> Result<Measurement, SolveError> {
+    let start = AllocationCount::now();
+    solution.solve(part, input)?;
+    let allocated = AllocationCount::now().since(start);
+
+    let mut times = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        solution.solve(part, input)?;
+        times.push(start.elapsed().as_nanos() as u64);
+    }
+    times.sort_unstable();
+
+    Ok(Measurement {
+        day: solution.day(),
+        part: part.number(),
+        runs,
+        min_ns: times.first().copied().unwrap_or(0),
+        median_ns: median(&times),
+        max_ns: times.last().copied().unwrap_or(0),
+        allocations: allocated.allocations,
+        allocated_bytes: allocated.bytes,
+    })
+}
+
+// Expects sorted times, with an even number of runs the two middle times are averaged
+fn median(times: &[u64]) -> u64 {
+    if times.is_empty() {
+        return 0;
+    }
+    let middle = times.len() / 2;
+    if times.len().is_multiple_of(2) {
+        (times[middle - 1] + times[middle]) / 2
+    } else {
+        times[middle]
+    }
+}
+
+// Compares the medians, a part is a regression when it is slower than the baseline by more
+// than `threshold_percent`. Parts missing from the baseline are not compared.
+pub fn find_regressions(
+    report: &Report,
+    baseline: &Report,
+    threshold_percent: f64,
+) -> Vec<Regression> {
+    report
+        .measurements
+        .iter()
+        .filter_map(|measurement| {
+            let previous = baseline.find(measurement.day, measurement.part)?;
+            let limit = previous.median_ns as f64 * (1.0 + threshold_percent / 100.0);
+            if measurement.median_ns as f64 > limit {
+                Some(Regression {
+                    day: measurement.day,
+                    part: measurement.part,
+                    baseline_ns: previous.median_ns,
+                    median_ns: measurement.median_ns,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+pub fn format_duration(nanoseconds: u64) -> String {
+    let nanoseconds = nanoseconds as f64;
+    if nanoseconds >= 1e9 {
+        format!("{:.2} s", nanoseconds / 1e9)
+    } else if nanoseconds >= 1e6 {
+        format!("{:.2} ms", nanoseconds / 1e6)
+    } else if nanoseconds >= 1e3 {
+        format!("{:.2} µs", nanoseconds / 1e3)
+    } else {
+        format!("{} ns", nanoseconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(day: u8, part: u8, median_ns: u64) -> Measurement {
+        Measurement {
+            day,
+            part,
+            runs: 3,
+            min_ns: median_ns,
+            median_ns,
+            max_ns: median_ns,
+            allocations: 0,
+            allocated_bytes: 0,
+        }
+    }
+
+    #[test]
+    fn check_median() {
+        assert_eq!(median(&[]), 0);
+        assert_eq!(median(&[1, 5, 9]), 5);
+        assert_eq!(median(&[1, 5, 7, 9]), 6);
+    }
+
+    #[test]
+    fn check_regressions() {
+        let baseline = Report {
+            measurements: vec![measurement(9, 1, 1000), measurement(9, 2, 1000)],
+        };
+        let report = Report {
+            measurements: vec![
+                measurement(9, 1, 1099),
+                measurement(9, 2, 1200),
+                measurement(10, 1, 5000),
+            ],
+        };
+        let regressions = find_regressions(&report, &baseline, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!((regressions[0].day, regressions[0].part), (9, 2));
+        assert!((regressions[0].slowdown_percent() - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn check_report_round_trip() {
+        let report = Report {
+            measurements: vec![measurement(1, 1, 42)],
+        };
+        let text = serde_json::to_string(&report).unwrap();
+        assert_eq!(serde_json::from_str::<Report>(&text).unwrap(), report);
+    }
+}
@@ -0,0 +1,46 @@
+use std::net::TcpListener;
+use std::process;
+use std::time::Duration;
+
+use aoc::server::{self, DEFAULT_ADDRESS};
+use aoc::timeout::parse_timeout;
+use aoc_common::error::EXIT_FAILURE;
+use aoc_common::logging;
+use clap::{ArgAction, Parser};
+
+#[derive(Parser)]
+#[command(
+    name = "aoc-server",
+    about = "Serves the Advent of Code 2025 solutions over HTTP on this machine"
+)]
+struct Cli {
+    /// Address to listen on, the loopback interface keeps the service local
+    #[arg(short, long, default_value = DEFAULT_ADDRESS)]
+    address: String,
+
+    /// Stops a part which runs longer than this many seconds and answers 503
+    #[arg(long, value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+
+    /// Logs the requests, repeat for the diagnostics of the solvers (-v info, -vv debug,
+    /// -vvv trace). Without it the level is read from $AOC_LOG
+    #[arg(short, long, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    logging::init(cli.verbose);
+
+    let listener = match TcpListener::bind(&cli.address) {
+        Ok(listener) => listener,
+        Err(error) => {
+            eprintln!("Error: Cannot listen on {}: {}", cli.address, error);
+            process::exit(EXIT_FAILURE);
+        }
+    };
+    if let Ok(address) = listener.local_addr() {
+        println!("Listening on http://{}", address);
+    }
+    server::serve(listener, cli.timeout);
+}
@@ -0,0 +1,166 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process;
+
+use aoc_common::input::workspace_root;
+
+use crate::fnv::{FNV_OFFSET, fnv1a};
+use crate::solution::Part;
+
+include!(concat!(env!("OUT_DIR"), "/solver_versions.rs"));
+
+// Answers of earlier runs, so running all the days again only solves the days which changed. An
+// answer is reused for the same day, part, solver version and input. The solver version is the
+// hash of the sources of the day's crate and of the code it shares with the others, computed by
+// build.rs, so any change of a day solves it again. Only answers are stored, failed and timed
+// out parts run again next time.
+
+pub const CACHE_VARIABLE: &str = "AOC_CACHE_DIR";
+
+pub fn solver_version(day: u8) -> Option<u64> {
+    SOLVER_VERSIONS
+        .get(usize::from(day).checked_sub(1)?)
+        .copied()
+        .flatten()
+}
+
+pub struct Cache {
+    directory: PathBuf,
+}
+
+impl Cache {
+    pub fn new(directory: PathBuf) -> Self {
+        Cache { directory }
+    }
+
+    // $AOC_CACHE_DIR when it is set, otherwise target/aoc-cache in the repository, which is
+    // removed by cargo clean as well
+    pub fn default_directory() -> PathBuf {
+        match env::var_os(CACHE_VARIABLE) {
+            Some(directory) => PathBuf::from(directory),
+            None => workspace_root().join("target").join("aoc-cache"),
+        }
+    }
+
+    pub fn directory(&self) -> &PathBuf {
+        &self.directory
+    }
+
+    fn prefix(day: u8, part: Part) -> String {
+        format!("day{:02}-part{}-", day, part)
+    }
+
+    // None for a day which the build script did not know about
+    fn path(&self, day: u8, part: Part, input: &str) -> Option<PathBuf> {
+        let version = solver_version(day)?;
+        let input_hash = fnv1a(FNV_OFFSET, input.as_bytes());
+        Some(self.directory.join(format!(
+            "{}{:016x}-{:016x}.txt",
+            Cache::prefix(day, part),
+            version,
+            input_hash
+        )))
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<String> {
+        fs::read_to_string(self.path(day, part, input)?).ok()
+    }
+
+    // Stores the answer and removes the answers of the older versions of the solver, they can
+    // never be used again
+    pub fn store(&self, day: u8, part: Part, input: &str, answer: &str) -> io::Result<()> {
+        let (Some(version), Some(path)) = (solver_version(day), self.path(day, part, input)) else {
+            return Ok(());
+        };
+        fs::create_dir_all(&self.directory)?;
+        let prefix = Cache::prefix(day, part);
+        let version_prefix = format!("{}{:016x}-", prefix, version);
+        for entry in fs::read_dir(&self.directory)?.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name.starts_with(&prefix) && !name.starts_with(&version_prefix) {
+                fs::remove_file(entry.path())?;
+            }
+        }
+        // Written next to the entry first, so a parallel run never reads half of an answer
+        let temporary = path.with_extension(format!("{}.tmp", process::id()));
+        fs::write(&temporary, answer)?;
+        fs::rename(&temporary, &path)
+    }
+
+    // Whether the file is one the cache wrote: an answer dayNN-partN-VERSION-INPUT.txt or the
+    // .tmp file of a run which stopped while storing it
+    fn is_entry(name: &str) -> bool {
+        let Some(rest) = name.strip_prefix("day") else {
+            return false;
+        };
+        let bytes = rest.as_bytes();
+        bytes.len() > 9
+            && bytes[..2].iter().all(u8::is_ascii_digit)
+            && rest[2..].starts_with("-part")
+            && bytes[7].is_ascii_digit()
+            && bytes[8] == b'-'
+            && (name.ends_with(".txt") || name.ends_with(".tmp"))
+    }
+
+    // Removes the stored answers and the leftovers of interrupted stores, returns how many files
+    // it removed. The directory and any other files in it stay, $AOC_CACHE_DIR could be shared.
+    pub fn clear(&self) -> io::Result<usize> {
+        let entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(error) => return Err(error),
+        };
+        let mut removed = 0;
+        for entry in entries {
+            let entry = entry?;
+            let is_entry = entry.file_name().to_str().is_some_and(Cache::is_entry);
+            if is_entry && entry.file_type()?.is_file() {
+                fs::remove_file(entry.path())?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_cache() {
+        let directory = env::temp_dir().join(format!("aoc-cache-test-{}", process::id()));
+        let cache = Cache::new(directory.clone());
+        assert!(solver_version(7).is_some());
+        assert_eq!(solver_version(25), None);
+        assert_eq!(cache.get(7, Part::One, "..S..\n"), None);
+
+        cache.store(7, Part::One, "..S..\n", "0").unwrap();
+        cache.store(7, Part::Two, "..S..\n", "1").unwrap();
+        assert_eq!(cache.get(7, Part::One, "..S..\n"), Some(String::from("0")));
+        assert_eq!(cache.get(7, Part::One, "..S..\n\n"), None);
+        assert_eq!(cache.get(8, Part::One, "..S..\n"), None);
+
+        // An answer of an older solver is replaced
+        let old = directory.join("day07-part1-0000000000000000-0000000000000000.txt");
+        fs::write(&old, "42").unwrap();
+        cache.store(7, Part::One, "..S..\n", "0").unwrap();
+        assert!(!old.exists());
+
+        // Only the files of the cache are removed, it could share the directory
+        let leftover = directory.join("day07-part2-0000000000000000-0000000000000000.123.tmp");
+        fs::write(&leftover, "1").unwrap();
+        let other = directory.join("day07-notes.txt");
+        fs::write(&other, "keep").unwrap();
+        fs::create_dir(directory.join("day07-part1-folder.txt")).unwrap();
+        assert_eq!(cache.clear().unwrap(), 3);
+        assert!(!leftover.exists());
+        assert!(other.exists());
+        assert!(directory.join("day07-part1-folder.txt").is_dir());
+        assert_eq!(cache.clear().unwrap(), 0);
+        fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(cache.clear().unwrap(), 0);
+    }
+}
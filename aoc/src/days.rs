@@ -0,0 +1,215 @@
+use crate::solution::{Part, Solution, SolveError};
+
+// Every day parses the whole input into its own model, the adapters only format the answers
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day01::solve_part_one(&day01::parse(input)?).to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day01::solve_part_two(&day01::parse(input)?).to_string())
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day02::solve_part_one(&day02::parse(input)?).to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day02::solve_part_two(&day02::parse(input)?).to_string())
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day03::solve_part_one(&day03::parse(input)?).to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day03::solve_part_two(&day03::parse(input)?).to_string())
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day04::solve_part_one(&day04::parse(input)?).to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day04::solve_part_two(&day04::parse(input)?).to_string())
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day05::solve_part_one(&day05::parse(input)?).to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day05::solve_part_two(&day05::parse(input)?).to_string())
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day06::solve_part_one(&day06::parse(input)?)?.to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day06::solve_part_two(&day06::parse(input)?)?.to_string())
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day07::solve_part_one(&day07::parse(input)?).to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day07::solve_part_two(&day07::parse(input)?).to_string())
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        // The puzzle connects the 1000 closest pairs of junction boxes
+        Ok(day08::solve_part_one(&day08::parse(input)?, 1000).to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day08::solve_part_two(&day08::parse(input)?).to_string())
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day09::solve_part_one(&day09::parse(input)?).to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day09::solve_part_two(&day09::parse(input)?)?.to_string())
+    }
+}
+
+impl From<day10::MachineError> for SolveError {
+    fn from(error: day10::MachineError) -> Self {
+        match error {
+            day10::MachineError::Cancelled => SolveError::Cancelled,
+            error => SolveError::NoAnswer(error.to_string()),
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day10::solve_part_one(&day10::parse(input)?)?.to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day10::solve_part_two(&day10::parse(input)?)?.to_string())
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day11::solve_part_one(&day11::parse(input)?).to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day11::solve_part_two(&day11::parse(input)?).to_string())
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        &[Part::One]
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(day12::solve_part_one(&day12::parse(input)?).to_string())
+    }
+}
+
+pub static SOLUTIONS: &[&dyn Solution] = &[
+    &Day01, &Day02, &Day03, &Day04, &Day05, &Day06, &Day07, &Day08, &Day09, &Day10, &Day11, &Day12,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn Solution> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.day() == day)
+        .copied()
+}
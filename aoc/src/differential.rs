@@ -0,0 +1,235 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::generators::Generator;
+use crate::parallel::panic_message;
+use crate::solution::{Part, Solution};
+
+// Differential testing: generated inputs are solved by the real solution of a day and by its
+// reference (see references.rs), any difference is shrunk to the smallest input which still shows
+// it. Inputs which the reference rejects are not interesting, they only come up while shrinking.
+
+// What a solution answered, a failure and a panic are answers as well
+fn answer(solution: &dyn Solution, part: Part, input: &str) -> Result<String, String> {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, input)));
+    match result {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(error)) => Err(error.to_string()),
+        Err(payload) => Err(format!("panicked: {}", panic_message(&*payload))),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: Part,
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub expected: String,
+    pub actual: Result<String, String>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let actual = match &self.actual {
+            Ok(answer) => answer.clone(),
+            Err(error) => format!("error ({})", error),
+        };
+        write!(
+            f,
+            "Day {:02} part {} answered {} instead of {} on the input generated with seed {} \
+             and size {}, shrunk to:\n{}",
+            self.day, self.part, actual, self.expected, self.seed, self.size, self.input
+        )
+    }
+}
+
+// Compares the answers on a single input, None when they agree or when the reference rejects it
+fn compare(
+    solution: &dyn Solution,
+    reference: &dyn Solution,
+    part: Part,
+    input: &str,
+) -> Option<(String, Result<String, String>)> {
+    let expected = answer(reference, part, input).ok()?;
+    let actual = answer(solution, part, input);
+    if actual.as_ref() == Ok(&expected) {
+        return None;
+    }
+    Some((expected, actual))
+}
+
+// Removes as many lines as possible, then as many comma separated items of the remaining lines,
+// as long as the input keeps failing. Chunks are halved until single lines are removed.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let mut lines: Vec<String> = input.lines().map(String::from).collect();
+    let join =
+        |lines: &[String]| -> String { lines.iter().map(|line| format!("{}\n", line)).collect() };
+
+    let mut chunk = lines.len().div_ceil(2).max(1);
+    loop {
+        let mut start = 0;
+        while start < lines.len() {
+            let end = (start + chunk).min(lines.len());
+            let mut candidate = lines.clone();
+            candidate.drain(start..end);
+            if !candidate.is_empty() && fails(&join(&candidate)) {
+                lines = candidate;
+            } else {
+                start = end;
+            }
+        }
+        if chunk == 1 {
+            break;
+        }
+        chunk = chunk.div_ceil(2);
+    }
+
+    for index in 0..lines.len() {
+        let mut items: Vec<String> = lines[index].split(',').map(String::from).collect();
+        let mut item = 0;
+        while items.len() > 1 && item < items.len() {
+            let mut candidate_items = items.clone();
+            candidate_items.remove(item);
+            let mut candidate = lines.clone();
+            candidate[index] = candidate_items.join(",");
+            if fails(&join(&candidate)) {
+                items = candidate_items;
+                lines = candidate;
+            } else {
+                item += 1;
+            }
+        }
+    }
+    join(&lines)
+}
+
+// Generates the input of the seed and compares both solutions on every part they share. On a
+// difference the smallest size with a difference is looked for first, then its input is shrunk.
+pub fn check(
+    solution: &dyn Solution,
+    reference: &dyn Solution,
+    generator: &Generator,
+    seed: u64,
+    size: usize,
+) -> Result<(), Mismatch> {
+    for part in Part::ALL {
+        if !solution.parts().contains(&part) || !reference.parts().contains(&part) {
+            continue;
+        }
+        let fails = |input: &str| compare(solution, reference, part, input).is_some();
+        if !fails(&generator.generate(seed, size)) {
+            continue;
+        }
+        let smallest_size = (1..size)
+            .find(|size| fails(&generator.generate(seed, *size)))
+            .unwrap_or(size);
+
+        let input = shrink(&generator.generate(seed, smallest_size), fails);
+        let (expected, actual) =
+            compare(solution, reference, part, &input).expect("the shrunk input fails");
+        return Err(Mismatch {
+            day: generator.day,
+            part,
+            seed,
+            size: smallest_size,
+            input,
+            expected,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::solution::SolveError;
+    use crate::{days, generators, references};
+
+    // Runs more seeds with AOC_DIFFERENTIAL_SEEDS, preferably with --release
+    fn seeds() -> u64 {
+        env::var("AOC_DIFFERENTIAL_SEEDS")
+            .ok()
+            .and_then(|seeds| seeds.parse().ok())
+            .unwrap_or(5)
+    }
+
+    fn check_day(day: u8, size: usize) {
+        let solution = days::find(day).unwrap();
+        let reference = references::find(day).unwrap();
+        let generator = generators::find(day).unwrap();
+        for seed in 0..seeds() {
+            if let Err(mismatch) = check(solution, reference, generator, seed, size) {
+                panic!("{}", mismatch);
+            }
+        }
+    }
+
+    #[test]
+    fn check_differential_days_01_to_04() {
+        check_day(1, 50);
+        check_day(2, 5);
+        check_day(3, 10);
+        check_day(4, 12);
+    }
+
+    #[test]
+    fn check_differential_days_05_to_08() {
+        check_day(5, 10);
+        check_day(6, 10);
+        check_day(7, 8);
+        check_day(8, 250);
+    }
+
+    #[test]
+    fn check_differential_days_09_to_11() {
+        check_day(9, 6);
+        check_day(10, 3);
+        check_day(11, 40);
+    }
+
+    // Answers the number of lines with a 7 in them, but stops counting at three
+    struct Broken;
+
+    impl Solution for Broken {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+            let sevens = input.lines().filter(|line| line.contains('7')).count();
+            Ok(sevens.min(3).to_string())
+        }
+    }
+
+    struct Counting;
+
+    impl Solution for Counting {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+            let sevens = input.lines().filter(|line| line.contains('7')).count();
+            Ok(sevens.to_string())
+        }
+    }
+
+    #[test]
+    fn check_mismatch_is_shrunk() {
+        let generator = generators::find(1).unwrap();
+        let mismatch = check(&Broken, &Counting, generator, 1, 100).unwrap_err();
+        assert_eq!(mismatch.part, Part::One);
+        assert_eq!(mismatch.input.lines().count(), 4);
+        assert!(mismatch.input.lines().all(|line| line.contains('7')));
+        assert_eq!(
+            (mismatch.expected.as_str(), mismatch.actual),
+            ("4", Ok(String::from("3")))
+        );
+        assert!(check(&Counting, &Counting, generator, 1, 100).is_ok());
+    }
+}
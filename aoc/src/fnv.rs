@@ -0,0 +1,12 @@
+// FNV-1a, a small hash which stays the same between builds and Rust versions unlike the hasher of
+// the standard library. It is shared with build.rs, so it cannot use anything of the crate.
+
+pub const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+// Continues the hash with the bytes, start with FNV_OFFSET
+pub fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+    })
+}
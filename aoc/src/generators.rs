@@ -0,0 +1,413 @@
+use rand::seq::SliceRandom;
+use rand::{Rng as _, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// Generators of random but valid inputs for every day, for stress and scale testing of the
+// solvers. The same seed and size always give the same input, so a failing input can be
+// reproduced from the two numbers alone.
+pub type Rng = ChaCha8Rng;
+
+pub struct Generator {
+    pub day: u8,
+    // What the size means for the day, shown by the generate command
+    pub size: &'static str,
+    // Size close to the one of the real puzzle input
+    pub default_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        let mut rng = Rng::seed_from_u64(seed);
+        (self.generate)(&mut rng, size)
+    }
+}
+
+pub static GENERATORS: [Generator; 12] = [
+    Generator {
+        day: 1,
+        size: "rotations of the dial",
+        default_size: 4000,
+        generate: generate_day01,
+    },
+    Generator {
+        day: 2,
+        size: "ID ranges",
+        default_size: 40,
+        generate: generate_day02,
+    },
+    Generator {
+        day: 3,
+        size: "battery banks of 100 digits",
+        default_size: 200,
+        generate: generate_day03,
+    },
+    Generator {
+        day: 4,
+        size: "width and height of the grid",
+        default_size: 140,
+        generate: generate_day04,
+    },
+    Generator {
+        day: 5,
+        size: "fresh ranges, there are five times as many ingredient IDs",
+        default_size: 180,
+        generate: generate_day05,
+    },
+    Generator {
+        day: 6,
+        size: "problems of the worksheet",
+        default_size: 1000,
+        generate: generate_day06,
+    },
+    Generator {
+        day: 7,
+        size: "rows with splitters",
+        default_size: 70,
+        generate: generate_day07,
+    },
+    Generator {
+        day: 8,
+        size: "junction boxes",
+        default_size: 1000,
+        generate: generate_day08,
+    },
+    Generator {
+        day: 9,
+        size: "columns of the polygon, it has four vertices more than twice as many",
+        default_size: 250,
+        generate: generate_day09,
+    },
+    Generator {
+        day: 10,
+        size: "machines",
+        default_size: 160,
+        generate: generate_day10,
+    },
+    Generator {
+        day: 11,
+        size: "devices besides svr, you, fft, dac and out",
+        default_size: 600,
+        generate: generate_day11,
+    },
+    Generator {
+        day: 12,
+        size: "regions under the tree",
+        default_size: 1000,
+        generate: generate_day12,
+    },
+];
+
+pub fn find(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+fn generate_day01(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let direction = if rng.random_bool(0.5) { 'L' } else { 'R' };
+        input.push_str(&format!("{}{}\n", direction, rng.random_range(1..1000)));
+    }
+    input
+}
+
+// Ranges are written on a single line, separated by commas
+fn generate_day02(rng: &mut Rng, size: usize) -> String {
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = rng.random_range(1..=10);
+            let from: u64 = rng.random_range(10u64.pow(digits - 1)..10u64.pow(digits));
+            let to = from + rng.random_range(0..=from.min(100_000));
+            format!("{}-{}", from, to)
+        })
+        .collect();
+    format!("{}\n", ranges.join(","))
+}
+
+fn generate_day03(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..100 {
+            input.push(char::from(b'0' + rng.random_range(1..=9)));
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn generate_day04(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push(if rng.random_bool(0.6) { '@' } else { '.' });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn generate_day05(rng: &mut Rng, size: usize) -> String {
+    const LARGEST_ID: u64 = 1_000_000_000_000_000;
+    let mut input = String::new();
+    for _ in 0..size {
+        let from = rng.random_range(1..LARGEST_ID);
+        let to = from + rng.random_range(0..LARGEST_ID / 100);
+        input.push_str(&format!("{}-{}\n", from, to));
+    }
+    input.push('\n');
+    for _ in 0..size * 5 {
+        input.push_str(&format!("{}\n", rng.random_range(1..LARGEST_ID)));
+    }
+    input
+}
+
+// Every problem has four numbers of up to four digits, aligned either to the left or to the
+// right of its column. The digits are never zero, a column of zeros would read as a separator.
+fn generate_day06(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![String::new(); 5];
+    for problem in 0..size {
+        let numbers: Vec<String> = (0..4)
+            .map(|_| {
+                let digits = rng.random_range(1..=4);
+                (0..digits)
+                    .map(|_| char::from(b'0' + rng.random_range(1..=9)))
+                    .collect()
+            })
+            .collect();
+        let width = numbers.iter().map(String::len).max().unwrap_or(1);
+        let left_aligned = rng.random_bool(0.5);
+        let operation = if rng.random_bool(0.5) { "*" } else { "+" };
+
+        let separator = if problem > 0 { " " } else { "" };
+        for (line, number) in lines.iter_mut().zip(numbers.iter()) {
+            line.push_str(separator);
+            if left_aligned {
+                line.push_str(&format!("{:<width$}", number));
+            } else {
+                line.push_str(&format!("{:>width$}", number));
+            }
+        }
+        lines[4].push_str(&format!("{}{:<width$}", separator, operation));
+    }
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+// Like the puzzle, the splitters are on every other row and the start is in the middle. The
+// columns of the splitters alternate with the rows, so the beams always hit them straight.
+// The timelines double with every splitter row, so large sizes overflow part two, which
+// --checked reports.
+fn generate_day07(rng: &mut Rng, size: usize) -> String {
+    let width = 2 * size + 3;
+    let middle = size + 1;
+    let mut input = String::new();
+    input.push_str(&format!(
+        "{}S{}\n",
+        ".".repeat(middle),
+        ".".repeat(width - middle - 1)
+    ));
+    for row in 0..size {
+        input.push_str(&".".repeat(width));
+        input.push('\n');
+        for column in 0..width {
+            let reachable =
+                column.abs_diff(middle) <= row && column.abs_diff(middle) % 2 == row % 2;
+            input.push(if reachable && rng.random_bool(0.5) {
+                '^'
+            } else {
+                '.'
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn generate_day08(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        input.push_str(&format!(
+            "{},{},{}\n",
+            rng.random_range(0..100_000),
+            rng.random_range(0..100_000),
+            rng.random_range(0..100_000)
+        ));
+    }
+    input
+}
+
+// A histogram: columns of increasing x with random heights, walked up one side of every column
+// and down to the bottom at the end, so the polygon is rectilinear and does not cross itself
+fn generate_day09(rng: &mut Rng, size: usize) -> String {
+    let mut x = rng.random_range(1000..2000);
+    let mut height = 0;
+    let mut points = vec![(x, 0)];
+    for _ in 0..size.max(1) {
+        let mut next_height = height;
+        while next_height == height {
+            next_height = rng.random_range(1000..100_000);
+        }
+        height = next_height;
+        points.push((x, height));
+        x += rng.random_range(1..400);
+        points.push((x, height));
+    }
+    points.push((x, 0));
+    points
+        .iter()
+        .map(|(x, y)| format!("{},{}\n", x, y))
+        .collect()
+}
+
+// The lights and the joltages come from pressing the buttons, so both parts have a solution
+fn generate_day10(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        // The solver of part two branches on every subset of the buttons with the right parity,
+        // so there are never many more buttons than lights, like in the puzzle
+        let light_count = rng.random_range(3..=10);
+        let button_count = rng.random_range(light_count - 2..=light_count + 2);
+        let mut indexes: Vec<usize> = (0..light_count).collect();
+        let buttons: Vec<Vec<usize>> = (0..button_count)
+            .map(|_| {
+                indexes.shuffle(rng);
+                let mut button = indexes[..rng.random_range(1..=light_count)].to_vec();
+                button.sort();
+                button
+            })
+            .collect();
+
+        let mut lights = vec![false; light_count];
+        let mut joltages = vec![0; light_count];
+        for button in buttons.iter() {
+            let presses = rng.random_range(0..=30);
+            for index in button.iter() {
+                joltages[*index] += presses;
+            }
+            if rng.random_bool(0.5) {
+                for index in button.iter() {
+                    lights[*index] = !lights[*index];
+                }
+            }
+        }
+
+        input.push('[');
+        input.extend(lights.iter().map(|light| if *light { '#' } else { '.' }));
+        input.push(']');
+        for button in buttons.iter() {
+            let indexes: Vec<String> = button.iter().map(usize::to_string).collect();
+            input.push_str(&format!(" ({})", indexes.join(",")));
+        }
+        let joltages: Vec<String> = joltages.iter().map(usize::to_string).collect();
+        input.push_str(&format!(" {{{}}}\n", joltages.join(",")));
+    }
+    input
+}
+
+// The devices are in a random order and lead only to the next few devices, so the graph has no
+// cycles and the paths are long. Devices right before fft and dac often lead straight to them, so
+// that some paths visit both. Only a few devices have two outputs, which keeps the path counts
+// far from overflowing.
+fn generate_day11(rng: &mut Rng, size: usize) -> String {
+    const REACH: usize = 8;
+    let name = |index: usize| {
+        let letters = [index / 676 % 26, index / 26 % 26, index % 26];
+        letters
+            .iter()
+            .map(|letter| char::from(b'a' + *letter as u8))
+            .collect::<String>()
+    };
+    // Names have three letters like in the puzzle, which limits how many devices there can be
+    let mut devices: Vec<String> = (0..size.min(26 * 26 * 26))
+        .map(name)
+        .filter(|device| !matches!(device.as_str(), "svr" | "you" | "fft" | "dac" | "out"))
+        .collect();
+    for special in ["you", "fft", "dac"] {
+        let position = rng.random_range(0..=devices.len());
+        devices.insert(position, String::from(special));
+    }
+    devices.insert(0, String::from("svr"));
+    devices.push(String::from("out"));
+
+    let branching_chance = (40.0 / devices.len() as f64).min(1.0);
+    let mut input = String::new();
+    for (index, device) in devices.iter().enumerate().take(devices.len() - 1) {
+        let reachable = &devices[index + 1..devices.len().min(index + 1 + REACH)];
+        let mut outputs: Vec<&str> = Vec::new();
+        if let Some(special) = reachable
+            .iter()
+            .find(|device| matches!(device.as_str(), "fft" | "dac"))
+            && rng.random_bool(0.5)
+        {
+            outputs.push(special);
+        } else {
+            outputs.push(&reachable[rng.random_range(0..reachable.len())]);
+        }
+        if rng.random_bool(branching_chance) {
+            let output = &reachable[rng.random_range(0..reachable.len())];
+            if !outputs.contains(&output.as_str()) {
+                outputs.push(output);
+            }
+        }
+        input.push_str(&format!("{}: {}\n", device, outputs.join(" ")));
+    }
+    input
+}
+
+// The shapes are the ones of the puzzle, only the regions below them are random. The regions are
+// as large as in the puzzle and have about as many presents, so some of them fit their presents
+// and some do not.
+fn generate_day12(rng: &mut Rng, size: usize) -> String {
+    const SHAPES: [&str; 6] = [
+        "##.\n###\n#.#",
+        "###\n..#\n###",
+        "###\n.#.\n###",
+        "###\n###\n..#",
+        "###\n.##\n..#",
+        "#..\n##.\n.##",
+    ];
+    let mut input = String::new();
+    for (index, shape) in SHAPES.iter().enumerate() {
+        input.push_str(&format!("{}:\n{}\n\n", index, shape));
+    }
+    for _ in 0..size {
+        let width = rng.random_range(35..=50);
+        let height = rng.random_range(35..=50);
+        let counts: Vec<String> = (0..SHAPES.len())
+            .map(|_| rng.random_range(10..=60).to_string())
+            .collect();
+        input.push_str(&format!("{}x{}: {}\n", width, height, counts.join(" ")));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn check_generated_inputs_are_solved() {
+        for generator in GENERATORS.iter() {
+            let solution = days::find(generator.day).unwrap();
+            let input = generator.generate(7, 12);
+            for part in solution.parts() {
+                if let Err(error) = solution.solve(*part, &input) {
+                    panic!(
+                        "Day {} part {} failed on a generated input: {}\n{}",
+                        generator.day, part, error, input
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn check_generated_inputs_are_reproducible() {
+        for generator in GENERATORS.iter() {
+            assert_eq!(generator.generate(3, 20), generator.generate(3, 20));
+            assert_ne!(generator.generate(3, 20), generator.generate(4, 20));
+        }
+        assert!(find(13).is_none());
+        assert_eq!(find(4).map(|generator| generator.day), Some(4));
+    }
+}
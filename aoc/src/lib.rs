@@ -0,0 +1,21 @@
+pub mod allocations;
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod days;
+pub mod differential;
+mod fnv;
+pub mod generators;
+pub mod output;
+pub mod parallel;
+pub mod references;
+pub mod scaffold;
+pub mod selection;
+pub mod server;
+pub mod solution;
+pub mod timeout;
+pub mod traces;
+pub mod viewer;
+pub mod watch;
+
+pub use solution::{Part, Solution, SolveError};
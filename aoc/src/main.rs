@@ -0,0 +1,1132 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::num::NonZero;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::error::EXIT_FAILURE;
+use aoc_common::input::workspace_root;
+use aoc_common::logging;
+use aoc_common::profile::{self, ALL_PROFILES, DEFAULT_PROFILE};
+use aoc_common::{AocError, Input, InputError, InputSource};
+use clap::{ArgAction, Args, Parser, Subcommand};
+use log::{info, warn};
+
+use aoc::allocations::CountingAllocator;
+use aoc::answers::{Answers, Status};
+use aoc::bench::{self, Measurement, Report, find_regressions, format_duration};
+use aoc::cache::Cache;
+use aoc::days;
+use aoc::generators;
+use aoc::output::{self, Format, PartResult, ResultStatus};
+use aoc::parallel::{JobEvent, RowStatus, SummaryTable, panic_message, run_jobs};
+use aoc::scaffold;
+use aoc::selection::parse_days;
+use aoc::solution::{Part, Solution, SolveError};
+use aoc::timeout::{parse_timeout, solve_with_timeout};
+use aoc::traces::{self, format_step};
+use aoc::viewer::{self, Player};
+use aoc::watch;
+
+// Counts the allocations of the solvers for the benchmarks
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2025 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Shows diagnostics of the solvers, repeat for more (-v info, -vv debug, -vvv trace).
+    /// Without it the level is read from $AOC_LOG
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solutions of the selected days
+    Run(RunArgs),
+    /// Measures how long the solutions of the selected days take
+    Bench(BenchArgs),
+    /// Compares the answers of the selected days with the accepted answers
+    Verify(VerifyArgs),
+    /// Writes a random but valid input of a day, the same seed always gives the same input
+    Generate(GenerateArgs),
+    /// Removes the answers which earlier runs stored in the cache
+    ClearCache,
+    /// Runs the tests and both parts of a day again whenever its sources or its input change
+    Watch(WatchArgs),
+    /// Creates the crate of a new day from a template and registers it in the workspace and the
+    /// runner, an existing day is never overwritten
+    New(NewArgs),
+    /// Records the events of a solver step by step into a trace, replays a trace or compares two
+    #[command(subcommand)]
+    Trace(TraceCommand),
+    /// Steps through the simulation of day 1, 4 or 7 in the terminal
+    View(ViewArgs),
+}
+
+#[derive(Args)]
+struct SelectionArgs {
+    /// Days to run: a single day (7), a range (3..5), a list (1,3,5) or "all"
+    days: String,
+
+    /// Runs only the selected part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Path to the input file ("-" reads standard input), can only be used when running a single
+    /// day. Without it the input is read from $AOC_INPUT_DIR/dayNN.txt or dayNN/input.txt
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Profile of the inputs and answers in inputs/YEAR/PROFILE, "default" for dayNN/input.txt
+    /// or "all" for every profile. Without it the profile is read from $AOC_PROFILE
+    #[arg(long, conflicts_with = "input", value_parser = parse_profile)]
+    profile: Option<String>,
+
+    /// Year of the profiles, the folder in inputs/ they are read from. Without it the year is
+    /// read from $AOC_YEAR, otherwise it is 2025
+    #[arg(long, value_parser = profile::parse_year)]
+    year: Option<u16>,
+}
+
+// Same names as the folders of the profiles, "all" selects every one of them
+fn parse_profile(name: &str) -> Result<String, String> {
+    match name {
+        ALL_PROFILES => Ok(String::from(name)),
+        name => profile::check_profile(name).map(|_| String::from(name)),
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: SelectionArgs,
+
+    /// Runs the parts in parallel on this many threads and shows a summary table. Without a
+    /// number as many parts run at the same time as there are cores
+    #[arg(short, long, num_args = 0..=1, default_missing_value = "0")]
+    jobs: Option<usize>,
+
+    /// Stops a part which runs longer than this many seconds and reports it as timed out,
+    /// the other parts still run
+    #[arg(long, value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+
+    /// Prints the results as text, as JSON or as CSV with the answers as strings
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Solves every part instead of reusing the answers of earlier runs from the cache, and does
+    /// not store the new answers. The cache is in $AOC_CACHE_DIR or target/aoc-cache
+    #[arg(long)]
+    no_cache: bool,
+
+    /// Reports a sum or product of a solver which overflows its integer as an error of the part
+    /// instead of printing the wrapped answer. Checked runs do not use the cache
+    #[arg(long)]
+    checked: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: SelectionArgs,
+
+    /// How many times every part runs
+    #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+
+    /// Writes the measurements to this JSON file
+    #[arg(long)]
+    report: Option<PathBuf>,
+
+    /// Compares the measurements with a report written by an earlier run
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// How many percent slower than the baseline a part can get before it is a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    #[command(flatten)]
+    selection: SelectionArgs,
+
+    /// File with the accepted answers, answers.toml in the root of the repository by default
+    #[arg(long)]
+    answers: Option<PathBuf>,
+
+    /// Stores the answers of the parts which do not have an accepted answer yet
+    #[arg(long)]
+    record: bool,
+
+    /// Stops a part which runs longer than this many seconds and reports it as timed out,
+    /// the other parts still run
+    #[arg(long, value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+
+    /// Reports a sum or product of a solver which overflows its integer as an error of the part
+    /// instead of comparing the wrapped answer
+    #[arg(long)]
+    checked: bool,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day to generate the input for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Seed of the random generator
+    #[arg(short, long, default_value_t = 0)]
+    seed: u64,
+
+    /// Size of the input, what it counts depends on the day (see the README).
+    /// Without it the input is about as large as the real one
+    #[arg(long)]
+    size: Option<usize>,
+
+    /// Writes the input to this file instead of the standard output
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Day to watch
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Path to the input file. Without it the input is read from $AOC_INPUT_DIR/dayNN.txt or
+    /// dayNN/input.txt
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Runs only the parts, not the tests of the day
+    #[arg(long)]
+    no_tests: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Subcommand)]
+enum TraceCommand {
+    /// Solves a part and writes the events of its solver to a trace
+    Record(RecordArgs),
+    /// Prints the events of a trace with their steps
+    Replay(ReplayArgs),
+    /// Compares two traces and shows the first step at which they diverge
+    Diff(DiffArgs),
+}
+
+#[derive(Args)]
+struct RecordArgs {
+    /// Day to record, days 1, 4, 7 and 8 record their steps
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Part to record
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Path to the input file ("-" reads standard input). Without it the input is read from
+    /// $AOC_INPUT_DIR/dayNN.txt or dayNN/input.txt
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Writes the trace to this file instead of the standard output
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct ReplayArgs {
+    /// Trace written by trace record
+    trace: PathBuf,
+
+    /// Shows only the events with this name, like "rotate"
+    #[arg(short, long)]
+    event: Option<String>,
+
+    /// Waits this many milliseconds after every event, to watch the solver step by step
+    #[arg(long, default_value_t = 0)]
+    delay: u64,
+}
+
+#[derive(Args)]
+struct DiffArgs {
+    /// Trace of the old version of the solver
+    old: PathBuf,
+
+    /// Trace of the new version of the solver
+    new: PathBuf,
+
+    /// How many events after the divergence are shown from both traces
+    #[arg(short, long, default_value_t = 3)]
+    context: usize,
+}
+
+#[derive(Args)]
+struct ViewArgs {
+    /// Day to view: 1 (the dial), 4 (the removal waves) or 7 (the beams)
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Path to the input file ("-" reads standard input). Without it the input is read from
+    /// $AOC_INPUT_DIR/dayNN.txt or dayNN/input.txt
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Steps per second while playing, + and - double and halve it
+    #[arg(long, default_value_t = 4)]
+    speed: u32,
+
+    /// Prints the frames as text instead of opening the viewer, every step or only the ones
+    /// given with --step
+    #[arg(long)]
+    headless: bool,
+
+    /// Step to print in headless mode, can be repeated
+    #[arg(long, requires = "headless")]
+    step: Vec<usize>,
+
+    /// Width and height of the headless frames
+    #[arg(long, num_args = 2, value_names = ["WIDTH", "HEIGHT"], default_values_t = [80, 24])]
+    size: Vec<usize>,
+}
+
+// Why the run failed, the exit code is the one of the first failure
+struct Failure {
+    message: String,
+    exit_code: i32,
+}
+
+// A failure which is not about the arguments or the input
+fn failure(message: String) -> Failure {
+    Failure {
+        message,
+        exit_code: EXIT_FAILURE,
+    }
+}
+
+impl From<AocError> for Failure {
+    fn from(error: AocError) -> Self {
+        Failure {
+            message: error.to_string(),
+            exit_code: error.exit_code(),
+        }
+    }
+}
+
+// Counts the parts which failed, so the remaining days and parts still run after a failure
+#[derive(Default)]
+struct Failures {
+    failed_parts: usize,
+    exit_code: Option<i32>,
+}
+
+impl Failures {
+    fn record(&mut self, parts: usize, exit_code: i32) {
+        self.failed_parts += parts;
+        self.exit_code.get_or_insert(exit_code);
+    }
+
+    fn into_result(self) -> Result<(), Failure> {
+        match self.exit_code {
+            Some(exit_code) => Err(Failure {
+                message: format!("{} of the selected parts failed", self.failed_parts),
+                exit_code,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+// Day of the selection with its loaded input and the parts which should run
+struct SelectedDay {
+    solution: &'static dyn Solution,
+    source: InputSource,
+    input: Result<Input, InputError>,
+    parts: Vec<Part>,
+    profile: String,
+    year: u16,
+    show_profile: bool,
+}
+
+impl SelectedDay {
+    // Day and profile for the messages, the profile is only shown when all the profiles run
+    fn title(&self) -> String {
+        let day = format!("Day {:02}", self.solution.day());
+        match self.show_profile {
+            true => format!("{} ({})", day, self.profile),
+            false => day,
+        }
+    }
+
+    fn profile_result(&self, mut result: PartResult) -> PartResult {
+        result.profile = Some(self.profile.clone());
+        result
+    }
+
+    // Every part of the day fails with the error of the input
+    fn input_failed(&self, error: &InputError, failures: &mut Failures) -> Vec<PartResult> {
+        let error = AocError::from(error.clone());
+        failures.record(self.parts.len(), error.exit_code());
+        eprintln!("{} failed: {}", self.title(), error);
+        self.parts
+            .iter()
+            .map(|part| {
+                self.profile_result(PartResult::failed(
+                    self.solution.day(),
+                    *part,
+                    ResultStatus::Error,
+                    error.to_string(),
+                    Duration::ZERO,
+                ))
+            })
+            .collect()
+    }
+
+    fn solve_failed(&self, part: Part, error: SolveError, failures: &mut Failures) -> String {
+        let (message, exit_code) = match error {
+            SolveError::Parse(error) => {
+                let error = AocError::from(error).in_file(self.source.name());
+                (error.to_string(), error.exit_code())
+            }
+            error => (error.to_string(), EXIT_FAILURE),
+        };
+        failures.record(1, exit_code);
+        eprintln!("{} part {} failed: {}", self.title(), part, message);
+        message
+    }
+
+    // Turns the outcome of a part into its result, a failure is reported and recorded on the way
+    fn part_result(
+        &self,
+        part: Part,
+        answer: Result<String, SolveError>,
+        elapsed: Duration,
+        failures: &mut Failures,
+    ) -> PartResult {
+        let day = self.solution.day();
+        self.profile_result(match answer {
+            Ok(answer) => PartResult::answered(day, part, answer, elapsed),
+            Err(error) => {
+                let status = match error {
+                    SolveError::TimedOut(_) => ResultStatus::Timeout,
+                    _ => ResultStatus::Error,
+                };
+                let message = self.solve_failed(part, error, failures);
+                PartResult::failed(day, part, status, message, elapsed)
+            }
+        })
+    }
+
+    // A panicking part fails on its own, the other parts still run
+    fn part_panicked(
+        &self,
+        part: Part,
+        message: &str,
+        elapsed: Duration,
+        failures: &mut Failures,
+    ) -> PartResult {
+        failures.record(1, EXIT_FAILURE);
+        eprintln!("{} part {} panicked: {}", self.title(), part, message);
+        let error = format!("panicked: {}", message);
+        let day = self.solution.day();
+        self.profile_result(PartResult::failed(
+            day,
+            part,
+            ResultStatus::Error,
+            error,
+            elapsed,
+        ))
+    }
+}
+
+fn select(args: &SelectionArgs) -> Result<Vec<SelectedDay>, Failure> {
+    let selected_days = parse_days(&args.days).map_err(AocError::Usage)?;
+    if args.input.is_some() && selected_days.len() > 1 {
+        return Err(AocError::Usage(String::from(
+            "--input can only be used when running a single day",
+        ))
+        .into());
+    }
+
+    let parts: Vec<Part> = match args.part {
+        Some(number) => Part::from_number(number).into_iter().collect(),
+        None => Part::ALL.to_vec(),
+    };
+
+    let year = match args.year {
+        Some(year) => year,
+        None => profile::env_year().map_err(AocError::Usage)?,
+    };
+    let all_profiles = args.profile.as_deref() == Some(ALL_PROFILES);
+    let profiles: Vec<Option<String>> = match (all_profiles, &args.profile) {
+        (true, _) => profile::profiles(year).into_iter().map(Some).collect(),
+        (false, Some(profile)) => vec![Some(profile.clone())],
+        (false, None) => vec![profile::env_profile().map_err(AocError::Usage)?],
+    };
+
+    let mut selection = Vec::new();
+    for profile in profiles.iter() {
+        for day in selected_days.iter().copied() {
+            let Some(solution) = days::find(day) else {
+                return Err(AocError::Usage(format!("Day {} is not solved yet", day)).into());
+            };
+            let source = InputSource::resolve_in_profile(
+                day,
+                args.input.as_deref(),
+                year,
+                profile.as_deref(),
+            );
+            let profile = profile.as_deref().unwrap_or(DEFAULT_PROFILE);
+            // A profile does not need the inputs of every day
+            if all_profiles
+                && profile != DEFAULT_PROFILE
+                && let InputSource::File(path) = &source
+                && !path.exists()
+            {
+                info!("Profile {} has no input for day {}", profile, day);
+                continue;
+            }
+            selection.push(SelectedDay {
+                solution,
+                input: source.load(),
+                source,
+                parts: parts
+                    .iter()
+                    .filter(|part| solution.parts().contains(part))
+                    .copied()
+                    .collect(),
+                profile: String::from(profile),
+                year,
+                show_profile: all_profiles,
+            });
+        }
+    }
+    Ok(selection)
+}
+
+// Reuses the answer of an earlier run when the cache has one, otherwise solves the part and stores
+// its answer. A cache which cannot be written only costs the next run some time.
+fn solve_cached(
+    cache: Option<&Cache>,
+    selected: &SelectedDay,
+    part: Part,
+    input: &str,
+    timeout: Option<Duration>,
+    checked: bool,
+) -> (Result<String, SolveError>, bool) {
+    let day = selected.solution.day();
+    if let Some(answer) = cache.and_then(|cache| cache.get(day, part, input)) {
+        return (Ok(answer), true);
+    }
+    let answer = solve_with_timeout(selected.solution, part, input, timeout, checked);
+    if let (Some(cache), Ok(answer)) = (cache, &answer)
+        && let Err(error) = cache.store(day, part, input, answer)
+    {
+        warn!(
+            "Cannot store the answer in {}: {}",
+            cache.directory().display(),
+            error
+        );
+    }
+    (answer, false)
+}
+
+fn print_results(format: Format, results: &[PartResult]) {
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", output::to_json(results)),
+        Format::Csv => print!("{}", output::to_csv(results)),
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), Failure> {
+    if let Some(jobs) = args.jobs {
+        return run_parallel(&args, jobs);
+    }
+
+    // A cached answer could have wrapped around, so checked runs solve everything
+    let cache = (!args.no_cache && !args.checked).then(|| Cache::new(Cache::default_directory()));
+    let mut failures = Failures::default();
+    let mut results = Vec::new();
+    let selected_days = select(&args.selection)?;
+    // The panics are reported with the part, the default hook would print them a second time
+    let panic_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    for selected in selected_days.iter() {
+        let input = match &selected.input {
+            Ok(input) => input,
+            Err(error) => {
+                results.extend(selected.input_failed(error, &mut failures));
+                continue;
+            }
+        };
+
+        for part in selected.parts.iter() {
+            let start = Instant::now();
+            let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+                solve_cached(
+                    cache.as_ref(),
+                    selected,
+                    *part,
+                    input.text(),
+                    args.timeout,
+                    args.checked,
+                )
+            }));
+            let (answer, cached) = match solved {
+                Ok(solved) => solved,
+                Err(payload) => {
+                    let message = panic_message(&*payload);
+                    let elapsed = start.elapsed();
+                    results.push(selected.part_panicked(*part, &message, elapsed, &mut failures));
+                    continue;
+                }
+            };
+            let mut result = selected.part_result(*part, answer, start.elapsed(), &mut failures);
+            result.cached = cached;
+            if let (Format::Text, Some(answer)) = (args.format, &result.answer) {
+                println!(
+                    "{} part {}: {}{}",
+                    selected.title(),
+                    part,
+                    answer,
+                    if cached { " (cached)" } else { "" }
+                );
+            }
+            results.push(result);
+        }
+    }
+    panic::set_hook(panic_hook);
+    print_results(args.format, &results);
+    failures.into_result()
+}
+
+// Every part of every selected day is a separate job, the answers are collected in a table
+fn run_parallel(args: &RunArgs, jobs: usize) -> Result<(), Failure> {
+    let threads = match jobs {
+        0 => thread::available_parallelism().map_or(1, NonZero::get),
+        jobs => jobs,
+    };
+
+    // A cached answer could have wrapped around, so checked runs solve everything
+    let cache = (!args.no_cache && !args.checked).then(|| Cache::new(Cache::default_directory()));
+    let mut failures = Failures::default();
+    let mut results = Vec::new();
+    let selected_days = select(&args.selection)?;
+    let mut parts: Vec<(&SelectedDay, Part, &str)> = Vec::new();
+    for selected in selected_days.iter() {
+        match &selected.input {
+            Ok(input) => parts.extend(
+                selected
+                    .parts
+                    .iter()
+                    .map(|part| (selected, *part, input.text())),
+            ),
+            Err(error) => results.extend(selected.input_failed(error, &mut failures)),
+        }
+    }
+
+    let rows: Vec<(u8, Part)> = parts
+        .iter()
+        .map(|(selected, part, _)| (selected.solution.day(), *part))
+        .collect();
+    let text = args.format == Format::Text;
+    let mut table = SummaryTable::new(&rows, text && io::stdout().is_terminal());
+    for (row, (selected, _, _)) in table.rows.iter_mut().zip(parts.iter()) {
+        row.profile = selected.show_profile.then(|| selected.profile.clone());
+    }
+    let mut outcomes = vec![None; parts.len()];
+    // The panics are reported in the table, the default hook would print over it
+    let panic_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let start = Instant::now();
+    run_jobs(
+        &parts,
+        threads,
+        |(selected, part, input)| {
+            solve_cached(
+                cache.as_ref(),
+                selected,
+                *part,
+                input,
+                args.timeout,
+                args.checked,
+            )
+        },
+        |index, event| match event {
+            JobEvent::Started => table.update(index, RowStatus::Running),
+            JobEvent::Finished((answer, cached), time) => {
+                match &answer {
+                    Ok(answer) => table.finish(index, Some(answer.clone()), time),
+                    Err(SolveError::TimedOut(_)) => table.time_out(index, time),
+                    Err(_) => table.finish(index, None, time),
+                }
+                if cached {
+                    table.update(index, RowStatus::Cached);
+                }
+                outcomes[index] = Some((Ok((answer, cached)), time));
+            }
+            JobEvent::Panicked(message, time) => {
+                table.finish(index, None, time);
+                outcomes[index] = Some((Err(message), time));
+            }
+        },
+    );
+    let elapsed = start.elapsed();
+    panic::set_hook(panic_hook);
+
+    if text {
+        if !table.is_live() {
+            print!("{}", table.render());
+        }
+        println!(
+            "Ran {} parts with {} thread(s) in {}, one after another they took {}",
+            parts.len(),
+            threads,
+            format_duration(elapsed.as_nanos() as u64),
+            format_duration(table.total_time().as_nanos() as u64)
+        );
+    }
+    for ((selected, part, _), outcome) in parts.iter().zip(outcomes) {
+        let Some((outcome, time)) = outcome else {
+            continue;
+        };
+        results.push(match outcome {
+            Ok((answer, cached)) => {
+                let mut result = selected.part_result(*part, answer, time, &mut failures);
+                result.cached = cached;
+                result
+            }
+            Err(message) => selected.part_panicked(*part, &message, time, &mut failures),
+        });
+    }
+    // Same order as a sequential run, the profiles in the order in which they were selected
+    let profile_order = |profile: &Option<String>| {
+        selected_days
+            .iter()
+            .position(|selected| Some(&selected.profile) == profile.as_ref())
+    };
+    results.sort_by_key(|result| (profile_order(&result.profile), result.day, result.part));
+    print_results(args.format, &results);
+    failures.into_result()
+}
+
+fn print_measurement(measurement: &Measurement) {
+    println!(
+        "{:>3} {:>4} {:>10} {:>10} {:>10} {:>11} {:>13}",
+        measurement.day,
+        measurement.part,
+        format_duration(measurement.min_ns),
+        format_duration(measurement.median_ns),
+        format_duration(measurement.max_ns),
+        measurement.allocations,
+        measurement.allocated_bytes
+    );
+}
+
+fn bench(args: BenchArgs) -> Result<(), Failure> {
+    // Read the baseline first, there is no point in measuring when it cannot be compared
+    let baseline = match &args.baseline {
+        Some(path) => Some(Report::load(path).map_err(AocError::Usage)?),
+        None => None,
+    };
+
+    if args.selection.profile.as_deref() == Some(ALL_PROFILES) {
+        return Err(AocError::Usage(String::from(
+            "bench measures a single profile, the measurements of all of them cannot be compared",
+        ))
+        .into());
+    }
+
+    let mut failures = Failures::default();
+    let mut report = Report::default();
+    println!(
+        "{:>3} {:>4} {:>10} {:>10} {:>10} {:>11} {:>13}",
+        "Day", "Part", "Min", "Median", "Max", "Allocations", "Bytes"
+    );
+    for selected in select(&args.selection)? {
+        let input = match &selected.input {
+            Ok(input) => input,
+            Err(error) => {
+                selected.input_failed(error, &mut failures);
+                continue;
+            }
+        };
+
+        for part in selected.parts.iter() {
+            match bench::measure(selected.solution, *part, input.text(), args.runs as usize) {
+                Ok(measurement) => {
+                    print_measurement(&measurement);
+                    report.measurements.push(measurement);
+                }
+                Err(error) => {
+                    selected.solve_failed(*part, error, &mut failures);
+                }
+            }
+        }
+    }
+
+    if let Some(path) = &args.report {
+        report.save(path).map_err(failure)?;
+    }
+    failures.into_result()?;
+
+    let Some(baseline) = baseline else {
+        return Ok(());
+    };
+    let regressions = find_regressions(&report, &baseline, args.threshold);
+    for regression in regressions.iter() {
+        println!(
+            "Day {:02} part {} is {:.1}% slower than the baseline ({} instead of {})",
+            regression.day,
+            regression.part,
+            regression.slowdown_percent(),
+            format_duration(regression.median_ns),
+            format_duration(regression.baseline_ns)
+        );
+    }
+    if !regressions.is_empty() {
+        return Err(failure(format!(
+            "{} of the measured parts regressed by more than {}%",
+            regressions.len(),
+            args.threshold
+        )));
+    }
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), Failure> {
+    let selection = select(&args.selection)?;
+    let show_profile = selection.iter().any(|selected| selected.show_profile);
+    if show_profile && args.answers.is_some() {
+        return Err(AocError::Usage(String::from(
+            "--answers cannot be used with all the profiles, every profile has its own answers",
+        ))
+        .into());
+    }
+    // Answers of the profiles by the path of their file, loaded when the profile runs first
+    let mut answers: BTreeMap<PathBuf, Answers> = BTreeMap::new();
+    let mut recorded: BTreeMap<PathBuf, usize> = BTreeMap::new();
+
+    let mut failures = Failures::default();
+    let profile_column = |profile: &str| match show_profile {
+        true => format!("{:<12} ", profile),
+        false => String::new(),
+    };
+    println!(
+        "{}{:>3} {:>4}  {:<7} {:>20} {:>20}",
+        profile_column("Profile"),
+        "Day",
+        "Part",
+        "Status",
+        "Expected",
+        "Actual"
+    );
+    for selected in selection.iter() {
+        let input = match &selected.input {
+            Ok(input) => input,
+            Err(error) => {
+                selected.input_failed(error, &mut failures);
+                continue;
+            }
+        };
+
+        let answers_path = args
+            .answers
+            .clone()
+            .unwrap_or_else(|| profile::answers_path(selected.year, &selected.profile));
+        if !answers.contains_key(&answers_path) {
+            let loaded = Answers::load(&answers_path).map_err(AocError::Usage)?;
+            answers.insert(answers_path.clone(), loaded);
+        }
+        let profile_answers = answers
+            .get_mut(&answers_path)
+            .expect("the answers of the profile are loaded");
+
+        let day = selected.solution.day();
+        for part in selected.parts.iter() {
+            let actual = match solve_with_timeout(
+                selected.solution,
+                *part,
+                input.text(),
+                args.timeout,
+                args.checked,
+            ) {
+                Ok(answer) => Some(answer),
+                Err(error) => {
+                    selected.solve_failed(*part, error, &mut failures);
+                    None
+                }
+            };
+            let expected = profile_answers.get(day, *part).map(String::from);
+            let status = Status::check(expected.as_deref(), actual.as_deref());
+            println!(
+                "{}{:>3} {:>4}  {:<7} {:>20} {:>20}",
+                profile_column(&selected.profile),
+                day,
+                part,
+                status,
+                expected.as_deref().unwrap_or("-"),
+                actual.as_deref().unwrap_or("-")
+            );
+
+            match (status, actual) {
+                (Status::Fail, _) => failures.record(1, EXIT_FAILURE),
+                (Status::Missing, Some(actual)) if args.record => {
+                    profile_answers.set(day, *part, &actual);
+                    *recorded.entry(answers_path.clone()).or_default() += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for (answers_path, recorded) in recorded.iter() {
+        answers[answers_path].save(answers_path).map_err(failure)?;
+        println!(
+            "Recorded {} answers in {}",
+            recorded,
+            answers_path.display()
+        );
+    }
+    failures.into_result()
+}
+
+fn generate(args: GenerateArgs) -> Result<(), Failure> {
+    let Some(generator) = generators::find(args.day) else {
+        return Err(AocError::Usage(format!("Day {} has no generator", args.day)).into());
+    };
+    let size = args.size.unwrap_or(generator.default_size);
+    let input = generator.generate(args.seed, size);
+    match &args.output {
+        Some(path) => fs::write(path, input)
+            .map_err(|error| failure(format!("Cannot write {}: {}", path.display(), error)))?,
+        None => print!("{}", input),
+    }
+    info!(
+        "Generated day {:02} with seed {} and {} {}",
+        args.day, args.seed, size, generator.size
+    );
+    Ok(())
+}
+
+fn clear_cache() -> Result<(), Failure> {
+    let cache = Cache::new(Cache::default_directory());
+    let removed = cache.clear().map_err(|error| {
+        failure(format!(
+            "Cannot clear {}: {}",
+            cache.directory().display(),
+            error
+        ))
+    })?;
+    println!(
+        "Removed {} files of the cache from {}",
+        removed,
+        cache.directory().display()
+    );
+    Ok(())
+}
+
+fn new(args: NewArgs) -> Result<(), Failure> {
+    let root = workspace_root();
+    let files = scaffold::new_day(&root, args.day).map_err(failure)?;
+    for file in files.iter() {
+        println!(
+            "Wrote {}",
+            file.strip_prefix(&root).unwrap_or(file).display()
+        );
+    }
+    println!(
+        "Put the input into day{:02}/input.txt and the example into day{:02}/README.md",
+        args.day, args.day
+    );
+    Ok(())
+}
+
+fn trace_record(args: RecordArgs) -> Result<(), Failure> {
+    let Some(solution) = days::find(args.day) else {
+        return Err(AocError::Usage(format!("Day {} is not solved yet", args.day)).into());
+    };
+    let Some(part) = Part::from_number(args.part).filter(|part| solution.parts().contains(part))
+    else {
+        return Err(
+            AocError::Usage(format!("Day {} does not have part {}", args.day, args.part)).into(),
+        );
+    };
+    let source = InputSource::resolve(args.day, args.input.as_deref()).map_err(AocError::Usage)?;
+    let input = source.load().map_err(AocError::from)?;
+    let trace = traces::record(solution, part, input.text()).map_err(|error| match error {
+        SolveError::Parse(error) => AocError::from(error).in_file(source.name()).into(),
+        error => failure(error.to_string()),
+    })?;
+
+    if trace.events.is_empty() {
+        warn!("Day {} does not record any events", args.day);
+    }
+    match &args.output {
+        Some(path) => {
+            traces::save(&trace, path).map_err(failure)?;
+            println!(
+                "Day {:02} part {}: {}, recorded {} events in {}",
+                args.day,
+                part,
+                trace.field("answer").unwrap_or_default(),
+                trace.events.len(),
+                path.display()
+            );
+        }
+        None => print!("{}", trace),
+    }
+    Ok(())
+}
+
+fn trace_replay(args: ReplayArgs) -> Result<(), Failure> {
+    let trace = traces::load(&args.trace).map_err(failure)?;
+    for (key, value) in trace.fields.iter() {
+        println!("{}: {}", key, value);
+    }
+    for (step, event) in traces::replay(&trace, args.event.as_deref()) {
+        println!("{}", format_step(step, event));
+        if args.delay > 0 {
+            thread::sleep(Duration::from_millis(args.delay));
+        }
+    }
+    Ok(())
+}
+
+fn trace_diff(args: DiffArgs) -> Result<(), Failure> {
+    let old = traces::load(&args.old).map_err(failure)?;
+    let new = traces::load(&args.new).map_err(failure)?;
+    let comparison = traces::compare(&old, &new);
+    for (key, old_value, new_value) in comparison.fields.iter() {
+        println!("{}: {} -> {}", key, old_value, new_value);
+    }
+    let Some(divergence) = &comparison.divergence else {
+        println!("The {} events of the traces are the same", old.events.len());
+        return match comparison.is_same() {
+            true => Ok(()),
+            false => Err(failure(String::from("The traces differ"))),
+        };
+    };
+
+    println!(
+        "The traces agree for {} events and diverge at step {}",
+        divergence.step - 1,
+        divergence.step
+    );
+    let start = divergence.step - 1;
+    for (label, trace) in [("old", &old), ("new", &new)] {
+        println!("{}:", label);
+        let events = trace.events.iter().enumerate().skip(start);
+        for (index, event) in events.take(args.context + 1) {
+            println!("{}", format_step(index + 1, event));
+        }
+        if trace.events.len() <= start {
+            println!("{:>7} (end of the trace)", "");
+        }
+    }
+    Err(failure(String::from("The traces differ")))
+}
+
+fn view(args: ViewArgs) -> Result<(), Failure> {
+    let Some(viewer) = viewer::find(args.day) else {
+        return Err(AocError::Usage(format!(
+            "Day {} cannot be viewed, only days 1, 4 and 7 can",
+            args.day
+        ))
+        .into());
+    };
+    let source = InputSource::resolve(args.day, args.input.as_deref()).map_err(AocError::Usage)?;
+    let input = source.load().map_err(AocError::from)?;
+    let simulation = viewer
+        .load(input.text())
+        .map_err(|error| AocError::from(error).in_file(source.name()))?;
+    let title = format!("Day {:02}", args.day);
+
+    if !args.headless {
+        if !io::stdout().is_terminal() {
+            return Err(AocError::Usage(String::from(
+                "The viewer needs a terminal, --headless prints the frames instead",
+            ))
+            .into());
+        }
+        return viewer::run(&title, simulation.as_ref(), args.speed)
+            .map_err(|error| failure(format!("The terminal failed: {}", error)));
+    }
+
+    let steps: Vec<usize> = match args.step.is_empty() {
+        true => (0..simulation.steps()).collect(),
+        false => args.step.clone(),
+    };
+    let mut player = Player::new(simulation.steps(), args.speed);
+    for (index, step) in steps.iter().enumerate() {
+        if *step >= simulation.steps() {
+            return Err(AocError::Usage(format!(
+                "Step {} is after the last step {}",
+                step,
+                simulation.steps() - 1
+            ))
+            .into());
+        }
+        player.seek(*step);
+        if index > 0 {
+            println!();
+        }
+        println!(
+            "{}",
+            viewer::render(
+                &title,
+                simulation.as_ref(),
+                &player,
+                (args.size[0], args.size[1])
+            )
+        );
+    }
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
+    logging::init(cli.verbose);
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+        Command::Generate(args) => generate(args),
+        Command::ClearCache => clear_cache(),
+        Command::New(args) => new(args),
+        Command::Trace(TraceCommand::Record(args)) => trace_record(args),
+        Command::Trace(TraceCommand::Replay(args)) => trace_replay(args),
+        Command::Trace(TraceCommand::Diff(args)) => trace_diff(args),
+        Command::View(args) => view(args),
+        Command::Watch(args) => {
+            watch::watch(args.day, args.input.as_deref(), !args.no_tests).map_err(failure)
+        }
+    };
+
+    if let Err(failure) = result {
+        eprintln!("Error: {}", failure.message);
+        process::exit(failure.exit_code);
+    }
+}
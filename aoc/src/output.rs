@@ -0,0 +1,195 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::solution::Part;
+
+// Machine readable results of the run command. The schema is stable: fields are only ever
+// added, and any other change bumps SCHEMA_VERSION. The answers are strings, so big integers
+// and the floats of day 9 reach other tools exactly as the runner printed them.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable lines or the summary table
+    #[default]
+    Text,
+    /// A single JSON object with the schema version and a list of results
+    Json,
+    /// A header line and one line per result
+    Csv,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultStatus {
+    Ok,
+    Error,
+    Timeout,
+}
+
+impl ResultStatus {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResultStatus::Ok => "ok",
+            ResultStatus::Error => "error",
+            ResultStatus::Timeout => "timeout",
+        }
+    }
+}
+
+// Result of one part, the answer is missing when the part failed and the error is missing
+// when it did not. Cached answers come from an earlier run (see cache.rs).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub status: ResultStatus,
+    pub answer: Option<String>,
+    pub elapsed_ns: u64,
+    pub error: Option<String>,
+    #[serde(default)]
+    pub cached: bool,
+    #[serde(default)]
+    pub profile: Option<String>,
+}
+
+impl PartResult {
+    pub fn answered(day: u8, part: Part, answer: String, elapsed: Duration) -> Self {
+        PartResult {
+            day,
+            part: part.number(),
+            status: ResultStatus::Ok,
+            answer: Some(answer),
+            elapsed_ns: elapsed.as_nanos() as u64,
+            error: None,
+            cached: false,
+            profile: None,
+        }
+    }
+
+    pub fn failed(
+        day: u8,
+        part: Part,
+        status: ResultStatus,
+        error: String,
+        elapsed: Duration,
+    ) -> Self {
+        PartResult {
+            day,
+            part: part.number(),
+            status,
+            answer: None,
+            elapsed_ns: elapsed.as_nanos() as u64,
+            error: Some(error),
+            cached: false,
+            profile: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonReport<'a> {
+    schema_version: u32,
+    results: Cow<'a, [PartResult]>,
+}
+
+pub fn to_json(results: &[PartResult]) -> String {
+    let report = JsonReport {
+        schema_version: SCHEMA_VERSION,
+        results: Cow::Borrowed(results),
+    };
+    serde_json::to_string_pretty(&report).expect("the results can always be serialized")
+}
+
+// Reads the output of --format json back, only the schema version of this runner is understood
+pub fn from_json(json: &str) -> Result<Vec<PartResult>, String> {
+    let report: JsonReport = serde_json::from_str(json).map_err(|error| error.to_string())?;
+    if report.schema_version != SCHEMA_VERSION {
+        return Err(format!(
+            "schema version {} is not supported, expected {}",
+            report.schema_version, SCHEMA_VERSION
+        ));
+    }
+    Ok(report.results.into_owned())
+}
+
+// Quotes the field when it holds a separator, a quote or a line break (RFC 4180)
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn to_csv(results: &[PartResult]) -> String {
+    let mut csv = String::from("day,part,status,answer,elapsed_ns,error,cached,profile\n");
+    for result in results.iter() {
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{},{},{},{}",
+            result.day,
+            result.part,
+            result.status.name(),
+            csv_field(result.answer.as_deref().unwrap_or_default()),
+            result.elapsed_ns,
+            csv_field(result.error.as_deref().unwrap_or_default()),
+            result.cached,
+            csv_field(result.profile.as_deref().unwrap_or_default())
+        );
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult::answered(
+                3,
+                Part::Two,
+                String::from("172981362045136"),
+                Duration::from_micros(584),
+            ),
+            PartResult::failed(
+                6,
+                Part::One,
+                ResultStatus::Error,
+                String::from("input.txt:2:5: \"6x4\" expected a number, found x"),
+                Duration::from_nanos(1200),
+            ),
+        ]
+    }
+
+    #[test]
+    fn check_json_output() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&results())).unwrap();
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(json["results"][0]["answer"], "172981362045136");
+        assert_eq!(json["results"][0]["elapsed_ns"], 584000);
+        assert_eq!(json["results"][0]["error"], serde_json::Value::Null);
+        assert_eq!(json["results"][0]["cached"], false);
+        assert_eq!(json["results"][1]["status"], "error");
+        assert_eq!(json["results"][1]["answer"], serde_json::Value::Null);
+
+        assert_eq!(from_json(&to_json(&results())), Ok(results()));
+        let future = r#"{"schema_version": 2, "results": []}"#;
+        assert!(from_json(future).unwrap_err().contains("version 2"));
+    }
+
+    #[test]
+    fn check_csv_output() {
+        assert_eq!(
+            to_csv(&results()),
+            "day,part,status,answer,elapsed_ns,error,cached,profile\n\
+             3,2,ok,172981362045136,584000,,false,\n\
+             6,1,error,,1200,\"input.txt:2:5: \"\"6x4\"\" expected a number, found x\",false,\n"
+        );
+    }
+}
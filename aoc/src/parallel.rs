@@ -0,0 +1,282 @@
+use std::any::Any;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::bench::format_duration;
+use crate::solution::Part;
+
+// Runs independent jobs on a pool of threads and shows their progress in a summary table, so
+// running all the days takes about as long as the slowest part instead of the sum of all parts.
+
+// The message a panic was started with, empty when it was not a string
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+// What happened to a job, reported from the threads of the pool as it happens
+pub enum JobEvent<R> {
+    Started,
+    Finished(R, Duration),
+    Panicked(String, Duration),
+}
+
+// Runs every job exactly once with at most `threads` jobs at the same time, the jobs are picked
+// in their order. The events are handed to `update` on the calling thread with the index of the
+// job, a panicking job is reported as such instead of taking the other jobs down.
+pub fn run_jobs<T, R, F, U>(jobs: &[T], threads: usize, work: F, mut update: U)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    U: FnMut(usize, JobEvent<R>),
+{
+    let next_job = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            let sender = sender.clone();
+            let (next_job, work) = (&next_job, &work);
+            scope.spawn(move || {
+                loop {
+                    let index = next_job.fetch_add(1, Ordering::SeqCst);
+                    let Some(job) = jobs.get(index) else {
+                        break;
+                    };
+                    let _ = sender.send((index, JobEvent::Started));
+                    let start = Instant::now();
+                    let event = match panic::catch_unwind(AssertUnwindSafe(|| work(job))) {
+                        Ok(result) => JobEvent::Finished(result, start.elapsed()),
+                        Err(payload) => {
+                            JobEvent::Panicked(panic_message(&*payload), start.elapsed())
+                        }
+                    };
+                    let _ = sender.send((index, event));
+                }
+            });
+        }
+        // Only the threads hold a sender now, the loop ends when all of them are done
+        drop(sender);
+        for (index, event) in receiver {
+            update(index, event);
+        }
+    });
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RowStatus {
+    Waiting,
+    Running,
+    Done,
+    Cached,
+    Failed,
+    TimedOut,
+}
+
+impl RowStatus {
+    fn name(&self) -> &'static str {
+        match self {
+            RowStatus::Waiting => "waiting",
+            RowStatus::Running => "running",
+            RowStatus::Done => "done",
+            RowStatus::Cached => "cached",
+            RowStatus::Failed => "FAILED",
+            RowStatus::TimedOut => "TIMEOUT",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    pub part: Part,
+    pub profile: Option<String>,
+    pub answer: Option<String>,
+    pub time: Option<Duration>,
+    pub status: RowStatus,
+}
+
+// Table with a row for every day and part. On a terminal it is drawn again in place whenever a
+// row changes, otherwise it is printed once when all the rows are finished.
+pub struct SummaryTable {
+    pub rows: Vec<Row>,
+    live: bool,
+    drawn_lines: usize,
+}
+
+impl SummaryTable {
+    pub fn new(jobs: &[(u8, Part)], live: bool) -> Self {
+        SummaryTable {
+            rows: jobs
+                .iter()
+                .map(|(day, part)| Row {
+                    day: *day,
+                    part: *part,
+                    profile: None,
+                    answer: None,
+                    time: None,
+                    status: RowStatus::Waiting,
+                })
+                .collect(),
+            live,
+            drawn_lines: 0,
+        }
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.live
+    }
+
+    // The profile is only shown when a row has one
+    pub fn render(&self) -> String {
+        let show_profile = self.rows.iter().any(|row| row.profile.is_some());
+        let profile_column = |profile: &str| match show_profile {
+            true => format!("{:<12} ", profile),
+            false => String::new(),
+        };
+        let mut lines = vec![format!(
+            "{}{:>3} {:>4} {:>20} {:>10}  {}",
+            profile_column("Profile"),
+            "Day",
+            "Part",
+            "Answer",
+            "Time",
+            "Status"
+        )];
+        for row in self.rows.iter() {
+            lines.push(format!(
+                "{}{:>3} {:>4} {:>20} {:>10}  {}",
+                profile_column(row.profile.as_deref().unwrap_or_default()),
+                row.day,
+                row.part,
+                row.answer.as_deref().unwrap_or("-"),
+                row.time
+                    .map(|time| format_duration(time.as_nanos() as u64))
+                    .unwrap_or_else(|| String::from("-")),
+                row.status.name()
+            ));
+        }
+        lines.iter().map(|line| format!("{}\n", line)).collect()
+    }
+
+    pub fn update(&mut self, index: usize, status: RowStatus) {
+        self.rows[index].status = status;
+        if self.live {
+            self.draw();
+        }
+    }
+
+    pub fn finish(&mut self, index: usize, answer: Option<String>, time: Duration) {
+        let row = &mut self.rows[index];
+        row.status = if answer.is_some() {
+            RowStatus::Done
+        } else {
+            RowStatus::Failed
+        };
+        row.answer = answer;
+        row.time = Some(time);
+        if self.live {
+            self.draw();
+        }
+    }
+
+    pub fn time_out(&mut self, index: usize, time: Duration) {
+        let row = &mut self.rows[index];
+        row.status = RowStatus::TimedOut;
+        row.time = Some(time);
+        if self.live {
+            self.draw();
+        }
+    }
+
+    // Moves the cursor back to the first line of the table and draws it over the old one
+    pub fn draw(&mut self) {
+        let rendered = self.render();
+        let mut stdout = io::stdout().lock();
+        if self.drawn_lines > 0 {
+            let _ = write!(stdout, "\x1b[{}F", self.drawn_lines);
+        }
+        for line in rendered.lines() {
+            let _ = writeln!(stdout, "\x1b[2K{}", line);
+        }
+        let _ = stdout.flush();
+        self.drawn_lines = rendered.lines().count();
+    }
+
+    // Total time of all the finished rows, the time a sequential run would have taken
+    pub fn total_time(&self) -> Duration {
+        self.rows.iter().filter_map(|row| row.time).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[test]
+    fn check_run_jobs() {
+        let jobs: Vec<u64> = (0..20).collect();
+        let running = AtomicUsize::new(0);
+        let most_running = Mutex::new(0);
+        let mut results = vec![None; jobs.len()];
+        let mut started = 0;
+        run_jobs(
+            &jobs,
+            3,
+            |job| {
+                let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                let mut most = most_running.lock().unwrap();
+                *most = (*most).max(now_running);
+                drop(most);
+                thread::sleep(Duration::from_millis(2));
+                running.fetch_sub(1, Ordering::SeqCst);
+                if *job == 13 {
+                    panic!("unlucky job");
+                }
+                job * 2
+            },
+            |index, event| match event {
+                JobEvent::Started => started += 1,
+                JobEvent::Finished(result, _) => results[index] = Some(Ok(result)),
+                JobEvent::Panicked(message, _) => results[index] = Some(Err(message)),
+            },
+        );
+
+        assert_eq!(started, 20);
+        assert!(*most_running.lock().unwrap() <= 3);
+        assert_eq!(results[4], Some(Ok(8)));
+        assert_eq!(results[13], Some(Err(String::from("unlucky job"))));
+        assert!(results.iter().all(Option::is_some));
+    }
+
+    #[test]
+    fn check_summary_table() {
+        let mut table = SummaryTable::new(&[(1, Part::One), (12, Part::One)], false);
+        table.update(1, RowStatus::Running);
+        table.finish(0, Some(String::from("997")), Duration::from_micros(1500));
+        assert_eq!(
+            table.render(),
+            "Day Part               Answer       Time  Status\n  \
+               1    1                  997    1.50 ms  done\n \
+              12    1                    -          -  running\n"
+        );
+        table.finish(1, None, Duration::from_millis(3));
+        assert_eq!(table.rows[1].status, RowStatus::Failed);
+        assert_eq!(table.total_time(), Duration::from_micros(4500));
+        table.time_out(1, Duration::from_secs(2));
+        assert!(table.render().ends_with("2.00 s  TIMEOUT\n"));
+
+        table.rows[1].profile = Some(String::from("alice"));
+        assert!(table.render().starts_with("Profile      Day Part"));
+        assert!(table.render().contains("\nalice         12    1 "));
+    }
+}
@@ -0,0 +1,748 @@
+use std::collections::BTreeMap;
+
+use aoc_common::ParseError;
+use aoc_common::grid::Grid;
+
+use crate::solution::{Solution, SolveError};
+
+// Slow but obviously correct solutions, the differential tests compare them with the real ones.
+// They share the parsers of the days and only replace the solvers, mostly by simulating the
+// puzzle step by step or by trying every possibility. Day 12 has no reference: its solver relies
+// on the regions of the puzzle being either roomy or clearly too small, checking whether the
+// presents can really be packed is not feasible.
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(turn_dial(&day01::parse(input)?).0.to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(turn_dial(&day01::parse(input)?).1.to_string())
+    }
+}
+
+// Turns the dial one click at a time, counts the rotations and the clicks which end at 0
+fn turn_dial(rotations: &[day01::Rotation]) -> (u64, u64) {
+    let mut position = 50;
+    let mut rotations_at_zero = 0;
+    let mut clicks_at_zero = 0;
+    for rotation in rotations.iter() {
+        for _ in 0..rotation.abs() {
+            position = (position + rotation.signum()).rem_euclid(100);
+            if position == 0 {
+                clicks_at_zero += 1;
+            }
+        }
+        if position == 0 {
+            rotations_at_zero += 1;
+        }
+    }
+    (rotations_at_zero, clicks_at_zero)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(sum_invalid_ids(&day02::parse(input)?, |repeats| repeats == 2).to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(sum_invalid_ids(&day02::parse(input)?, |repeats| repeats >= 2).to_string())
+    }
+}
+
+// Builds the ID from the repeated sequence and compares, instead of comparing the slices
+fn sum_invalid_ids(ranges: &[day02::Range], allowed_repeats: fn(usize) -> bool) -> u64 {
+    let mut sum = 0;
+    for range in ranges.iter() {
+        for id in range.0..=range.1 {
+            let id_string = id.to_string();
+            let invalid = (1..id_string.len()).any(|length| {
+                let repeats = id_string.len() / length;
+                allowed_repeats(repeats) && id_string[..length].repeat(repeats) == id_string
+            });
+            if invalid {
+                sum += id;
+            }
+        }
+    }
+    sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        let banks = day03::parse(input)?;
+        Ok(banks
+            .iter()
+            .map(|bank| largest_joltage(bank, 2))
+            .sum::<u64>()
+            .to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        let banks = day03::parse(input)?;
+        Ok(banks
+            .iter()
+            .map(|bank| largest_joltage(bank, 12))
+            .sum::<u64>()
+            .to_string())
+    }
+}
+
+// largest[count] is the largest joltage of count batteries from the batteries seen so far,
+// every battery either extends the best joltage of one battery less or is skipped
+fn largest_joltage(bank: &[u32], batteries: usize) -> u64 {
+    let mut largest: Vec<Option<u64>> = vec![None; batteries + 1];
+    largest[0] = Some(0);
+    for battery in bank.iter() {
+        for count in (1..=batteries).rev() {
+            if let Some(joltage) = largest[count - 1] {
+                let joltage = joltage * 10 + *battery as u64;
+                largest[count] = largest[count].max(Some(joltage));
+            }
+        }
+    }
+    largest[batteries].unwrap_or(0)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        let map = rows(&day04::parse(input)?);
+        let accessible = rolls(&map)
+            .filter(|(row, column)| is_accessible(&map, *row, *column))
+            .count();
+        Ok(accessible.to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        // Removes a single roll at a time, the order does not change which rolls end up removed
+        let mut map = rows(&day04::parse(input)?);
+        let mut removed = 0;
+        loop {
+            let accessible = rolls(&map).find(|(row, column)| is_accessible(&map, *row, *column));
+            let Some((row, column)) = accessible else {
+                break;
+            };
+            map[row][column] = false;
+            removed += 1;
+        }
+        Ok(removed.to_string())
+    }
+}
+
+// The reference does not use the neighbours of the grid, which the solution relies on
+fn rows(map: &day04::Map) -> Vec<Vec<bool>> {
+    map.rows().map(<[bool]>::to_vec).collect()
+}
+
+fn rolls(map: &[Vec<bool>]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    map.iter().enumerate().flat_map(|(row, cells)| {
+        cells
+            .iter()
+            .enumerate()
+            .filter(|(_, is_roll)| **is_roll)
+            .map(move |(column, _)| (row, column))
+    })
+}
+
+fn is_accessible(map: &[Vec<bool>], row: usize, column: usize) -> bool {
+    let neighbours = rolls(map)
+        .filter(|(other_row, other_column)| {
+            (*other_row, *other_column) != (row, column)
+                && other_row.abs_diff(row) <= 1
+                && other_column.abs_diff(column) <= 1
+        })
+        .count();
+    neighbours < 4
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        let inventory = day05::parse(input)?;
+        let fresh = inventory
+            .ingredient_ids
+            .iter()
+            .filter(|id| {
+                inventory
+                    .ranges
+                    .iter()
+                    .any(|range| (range.from..=range.to).contains(*id))
+            })
+            .count();
+        Ok(fresh.to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        // Sweeps the ranges ordered by their start, counting only the IDs after the last counted
+        // one
+        let mut ranges = day05::parse(input)?.ranges;
+        ranges.sort_by_key(|range| range.from);
+        let mut count = 0;
+        let mut next_uncounted = 0;
+        for range in ranges.iter() {
+            let from = range.from.max(next_uncounted);
+            if from <= range.to {
+                count += range.to - from + 1;
+                next_uncounted = range.to + 1;
+            }
+        }
+        Ok(count.to_string())
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        solve_worksheet(input, |block| {
+            block
+                .iter()
+                .map(|row| row.iter().collect::<String>())
+                .collect()
+        })
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        solve_worksheet(input, |block| {
+            (0..block[0].len())
+                .map(|column| block.iter().map(|row| row[column]).collect::<String>())
+                .collect()
+        })
+    }
+}
+
+// Cuts the worksheet into blocks of columns between the empty columns, every block is one problem.
+// The numbers of a block are read by the given function, with the spaces left in them.
+fn solve_worksheet(
+    input: &str,
+    read_numbers: fn(&[Vec<char>]) -> Vec<String>,
+) -> Result<String, SolveError> {
+    let worksheet = day06::parse(input)?;
+    let rows: Vec<Vec<char>> = worksheet
+        .number_lines
+        .iter()
+        .map(|line| line.chars().collect())
+        .collect();
+    let operations: Vec<char> = worksheet.operations_line.chars().collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let is_empty_column = |column: usize| {
+        rows.iter()
+            .all(|row| row.get(column).is_none_or(|cell| *cell == ' '))
+    };
+
+    let mut total: u64 = 0;
+    let mut start = 0;
+    while start < width {
+        let end = (start..width)
+            .find(|column| is_empty_column(*column))
+            .unwrap_or(width);
+        let block: Vec<Vec<char>> = rows
+            .iter()
+            .map(|row| {
+                (start..end)
+                    .map(|column| row.get(column).copied().unwrap_or(' '))
+                    .collect()
+            })
+            .collect();
+        let numbers: Vec<u64> = read_numbers(&block)
+            .iter()
+            .map(|number| number.trim())
+            .filter(|number| !number.is_empty())
+            .map(|number| number.parse::<u64>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| ParseError::new(1, start + 1, "", "expected numbers in the problem"))?;
+        let operation = (start..end)
+            .filter_map(|column| operations.get(column))
+            .find(|operation| **operation != ' ');
+        total += match operation {
+            Some('*') => numbers.iter().product::<u64>(),
+            Some('+') => numbers.iter().sum::<u64>(),
+            _ => {
+                return Err(ParseError::new(
+                    rows.len() + 1,
+                    start + 1,
+                    "",
+                    "expected operation * or +",
+                )
+                .into());
+            }
+        };
+        start = end + 1;
+    }
+    Ok(total.to_string())
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        Ok(follow_beams(day07::parse(input)?.grid()).0.to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        Ok(follow_beams(day07::parse(input)?.grid()).1.to_string())
+    }
+}
+
+// Moves the beams down one row at a time, with the number of timelines in every column. Returns
+// how many times a beam was split and how many timelines leave the manifold.
+fn follow_beams(grid: &Grid<day07::Cell>) -> (u64, u64) {
+    let width = grid.width();
+    let mut timelines: Vec<u64> = grid
+        .row(0)
+        .iter()
+        .map(|cell| if *cell == day07::Cell::Start { 1 } else { 0 })
+        .collect();
+    let mut splits = 0;
+    for row in grid.rows().skip(1) {
+        let mut next_timelines = vec![0; width];
+        for (column, cell) in row.iter().enumerate() {
+            if timelines[column] == 0 {
+                continue;
+            }
+            if *cell != day07::Cell::Splitter {
+                next_timelines[column] += timelines[column];
+                continue;
+            }
+            splits += 1;
+            if column > 0 {
+                next_timelines[column - 1] += timelines[column];
+            }
+            if column + 1 < width {
+                next_timelines[column + 1] += timelines[column];
+            }
+        }
+        timelines = next_timelines;
+    }
+    (splits, timelines.iter().sum())
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        let boxes = day08::parse(input)?;
+        let mut circuits: Vec<usize> = (0..boxes.len()).collect();
+        for (from, to) in closest_pairs(&boxes).into_iter().take(1000) {
+            join_circuits(&mut circuits, from, to);
+        }
+
+        let mut sizes: BTreeMap<usize, u64> = BTreeMap::new();
+        for circuit in circuits.iter() {
+            *sizes.entry(*circuit).or_default() += 1;
+        }
+        let mut sizes: Vec<u64> = sizes.into_values().collect();
+        sizes.sort();
+        if sizes.len() < 3 {
+            return Ok(0.to_string());
+        }
+        Ok(sizes.iter().rev().take(3).product::<u64>().to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        let boxes = day08::parse(input)?;
+        let mut circuits: Vec<usize> = (0..boxes.len()).collect();
+        for (from, to) in closest_pairs(&boxes) {
+            join_circuits(&mut circuits, from, to);
+            if circuits.iter().all(|circuit| *circuit == circuits[0]) {
+                return Ok((boxes[from].x * boxes[to].x).to_string());
+            }
+        }
+        Ok(0.to_string())
+    }
+}
+
+// All pairs of the boxes, the closest first. The squared distance is exact, unlike the floats.
+fn closest_pairs(boxes: &[day08::Box]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for from in 0..boxes.len() {
+        for to in from + 1..boxes.len() {
+            let (a, b) = (&boxes[from], &boxes[to]);
+            let distance =
+                a.x.abs_diff(b.x).pow(2) + a.y.abs_diff(b.y).pow(2) + a.z.abs_diff(b.z).pow(2);
+            pairs.push((distance, from, to));
+        }
+    }
+    pairs.sort();
+    pairs.into_iter().map(|(_, from, to)| (from, to)).collect()
+}
+
+// Every box holds the number of its circuit, joining moves all boxes of one circuit to the other
+fn join_circuits(circuits: &mut [usize], from: usize, to: usize) {
+    let (old, new) = (circuits[to], circuits[from]);
+    for circuit in circuits.iter_mut() {
+        if *circuit == old {
+            *circuit = new;
+        }
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        let points = day09::parse(input)?;
+        Ok(largest_rectangle(&points, |_, _| true).to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        let points = day09::parse(input)?;
+        check_rectilinear(&points)?;
+        Ok(largest_rectangle(&points, |a, b| rectangle_inside_polygon(&points, a, b)).to_string())
+    }
+}
+
+fn largest_rectangle(
+    points: &[day09::Point],
+    allowed: impl Fn(&day09::Point, &day09::Point) -> bool,
+) -> u64 {
+    let mut largest = 0;
+    for (index, a) in points.iter().enumerate() {
+        for b in points.iter().skip(index + 1) {
+            let area = (a.x.abs_diff(b.x) + 1) * (a.y.abs_diff(b.y) + 1);
+            if area > largest && allowed(a, b) {
+                largest = area;
+            }
+        }
+    }
+    largest
+}
+
+// The red tiles are the corners of the polygon, every one is in line with the next one
+fn check_rectilinear(points: &[day09::Point]) -> Result<(), ParseError> {
+    if points.len() < 4 {
+        return Err(ParseError::new(1, 1, "", "expected at least four corners"));
+    }
+    for (index, a) in points.iter().enumerate() {
+        let b = &points[(index + 1) % points.len()];
+        if (a.x == b.x) == (a.y == b.y) {
+            return Err(ParseError::new(
+                index + 1,
+                1,
+                "",
+                "expected the corner to be in line with the next one",
+            ));
+        }
+    }
+    Ok(())
+}
+
+// The polygon only changes between the coordinates of its corners, so the rectangle lies inside
+// when all the points at and between the coordinates of the corners inside of it do
+fn rectangle_inside_polygon(points: &[day09::Point], a: &day09::Point, b: &day09::Point) -> bool {
+    let coordinates = |values: Vec<u64>, from: u64, to: u64| {
+        let mut values: Vec<u64> = values
+            .into_iter()
+            .filter(|value| (from..=to).contains(value))
+            .map(|value| value * 2)
+            .collect();
+        values.sort();
+        values.dedup();
+        let middles: Vec<u64> = values
+            .windows(2)
+            .map(|pair| (pair[0] + pair[1]) / 2)
+            .collect();
+        values.extend(middles);
+        values
+    };
+    let xs = coordinates(
+        points.iter().map(|point| point.x).collect(),
+        a.x.min(b.x),
+        a.x.max(b.x),
+    );
+    let ys = coordinates(
+        points.iter().map(|point| point.y).collect(),
+        a.y.min(b.y),
+        a.y.max(b.y),
+    );
+    xs.iter()
+        .all(|x| ys.iter().all(|y| inside_polygon(points, *x, *y)))
+}
+
+// Whether the point, with doubled coordinates, is inside of the polygon or on its border
+fn inside_polygon(points: &[day09::Point], x: u64, y: u64) -> bool {
+    let mut crossings = 0;
+    for (index, a) in points.iter().enumerate() {
+        let b = &points[(index + 1) % points.len()];
+        let (ax, ay, bx, by) = (a.x * 2, a.y * 2, b.x * 2, b.y * 2);
+        let on_border = (ax.min(bx)..=ax.max(bx)).contains(&x)
+            && (ay.min(by)..=ay.max(by)).contains(&y)
+            && (ax == bx || ay == by);
+        if on_border {
+            return true;
+        }
+        // Counts the vertical borders to the right of the point, the half open range of the
+        // border makes a corner count once
+        if ax == bx && ax > x && (ay.min(by)..ay.max(by)).contains(&y) {
+            crossings += 1;
+        }
+    }
+    crossings % 2 == 1
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        let machines = day10::parse(input)?;
+        let mut presses = 0;
+        for machine in machines.iter() {
+            // Pressing a button twice does nothing, so every subset of the buttons is tried once
+            let buttons = machine.buttons.len();
+            let fewest = (0..1u32 << buttons)
+                .filter(|subset| {
+                    let mut lights = vec![false; machine.lights.len()];
+                    for (button, indexes) in machine.buttons.iter().enumerate() {
+                        if subset & (1 << button) != 0 {
+                            for index in indexes.iter() {
+                                lights[*index as usize] ^= true;
+                            }
+                        }
+                    }
+                    lights == machine.lights
+                })
+                .map(u32::count_ones)
+                .min();
+            let Some(fewest) = fewest else {
+                return Err(SolveError::Parse(ParseError::new(
+                    0,
+                    1,
+                    "",
+                    "expected the lights to be reachable",
+                )));
+            };
+            presses += fewest as u64;
+        }
+        Ok(presses.to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        let machines = day10::parse(input)?;
+        let mut presses = 0;
+        for machine in machines.iter() {
+            let Some(fewest) = fewest_presses_for_joltages(machine) else {
+                return Err(SolveError::Parse(ParseError::new(
+                    0,
+                    1,
+                    "",
+                    "expected the joltages to be reachable",
+                )));
+            };
+            presses += fewest;
+        }
+        Ok(presses.to_string())
+    }
+}
+
+// The presses of the buttons solve a linear system, one equation for every counter. Elimination
+// leaves a few free buttons, the presses of the other buttons follow from them. Every number of
+// presses of the free buttons is tried, up to the smallest joltage the button adds to.
+fn fewest_presses_for_joltages(machine: &day10::Machine) -> Option<u64> {
+    let buttons = machine.buttons.len();
+    let mut rows: Vec<Vec<i64>> = machine
+        .joltage_requirements
+        .iter()
+        .enumerate()
+        .map(|(counter, joltage)| {
+            let mut row: Vec<i64> = machine
+                .buttons
+                .iter()
+                .map(|button| button.contains(&(counter as u32)) as i64)
+                .collect();
+            row.push(*joltage as i64);
+            row
+        })
+        .collect();
+    let limits: Vec<i64> = machine
+        .buttons
+        .iter()
+        .map(|button| {
+            button
+                .iter()
+                .map(|counter| machine.joltage_requirements[*counter as usize] as i64)
+                .min()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    // Integer elimination, every pivot row ends up with zeros in the columns of the other pivots
+    let mut pivots: Vec<(usize, usize)> = Vec::new();
+    for column in 0..buttons {
+        let Some(row) = (pivots.len()..rows.len()).find(|row| rows[*row][column] != 0) else {
+            continue;
+        };
+        rows.swap(pivots.len(), row);
+        let pivot_row = rows[pivots.len()].clone();
+        for (index, row) in rows.iter_mut().enumerate() {
+            if index == pivots.len() || row[column] == 0 {
+                continue;
+            }
+            let factor = row[column];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row.iter()) {
+                *value = *value * pivot_row[column] - pivot_value * factor;
+            }
+            let divisor = row
+                .iter()
+                .fold(0, |divisor, value| gcd(divisor, value.abs()));
+            if divisor > 1 {
+                row.iter_mut().for_each(|value| *value /= divisor);
+            }
+        }
+        pivots.push((pivots.len(), column));
+    }
+    if rows[pivots.len()..].iter().any(|row| row[buttons] != 0) {
+        return None;
+    }
+
+    let free: Vec<usize> = (0..buttons)
+        .filter(|button| pivots.iter().all(|(_, column)| column != button))
+        .collect();
+    let mut presses = vec![0; buttons];
+    let mut fewest = None;
+    try_free_presses(&rows, &pivots, &free, &limits, &mut presses, &mut fewest);
+    fewest
+}
+
+fn try_free_presses(
+    rows: &[Vec<i64>],
+    pivots: &[(usize, usize)],
+    free: &[usize],
+    limits: &[i64],
+    presses: &mut Vec<i64>,
+    fewest: &mut Option<u64>,
+) {
+    if let Some((button, free)) = free.split_first() {
+        for count in 0..=limits[*button] {
+            presses[*button] = count;
+            try_free_presses(rows, pivots, free, limits, presses, fewest);
+        }
+        presses[*button] = 0;
+        return;
+    }
+
+    let buttons = presses.len();
+    let mut total = presses.iter().sum::<i64>();
+    for (row, column) in pivots.iter() {
+        let row = &rows[*row];
+        let rest: i64 = (0..buttons)
+            .filter(|button| button != column)
+            .map(|button| row[button] * presses[button])
+            .sum();
+        let remaining = row[buttons] - rest;
+        if remaining % row[*column] != 0 || remaining / row[*column] < 0 {
+            return;
+        }
+        total += remaining / row[*column];
+    }
+    *fewest = Some(fewest.map_or(total as u64, |fewest: u64| fewest.min(total as u64)));
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+        let graph = day11::parse(input)?;
+        Ok(count_paths(&graph, "you", &[]).to_string())
+    }
+
+    fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+        let graph = day11::parse(input)?;
+        Ok(count_paths(&graph, "svr", &["dac", "fft"]).to_string())
+    }
+}
+
+// Walks every single path from out back to the start, the parsed edges point backwards
+fn count_paths(graph: &day11::Graph, start: &str, required: &[&str]) -> u64 {
+    fn walk(
+        graph: &day11::Graph,
+        device: &str,
+        start: &str,
+        path: &mut Vec<String>,
+        required: &[&str],
+    ) -> u64 {
+        if device == start {
+            let visits_all = required
+                .iter()
+                .all(|required| path.iter().any(|device| device == required));
+            return visits_all as u64;
+        }
+        let Some(inputs) = graph.get(device) else {
+            return 0;
+        };
+        let mut paths = 0;
+        for input in inputs.iter() {
+            path.push(input.clone());
+            paths += walk(graph, input, start, path, required);
+            path.pop();
+        }
+        paths
+    }
+    walk(graph, "out", start, &mut Vec::new(), required)
+}
+
+pub static REFERENCES: [&dyn Solution; 11] = [
+    &Day01, &Day02, &Day03, &Day04, &Day05, &Day06, &Day07, &Day08, &Day09, &Day10, &Day11,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn Solution> {
+    REFERENCES
+        .iter()
+        .find(|reference| reference.day() == day)
+        .copied()
+}
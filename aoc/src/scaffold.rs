@@ -0,0 +1,291 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Creates the crate of a new day from the template in aoc/templates/day and registers it in the
+// workspace and the runner. Nothing is written when the day exists in any of those places, so an
+// existing day is never overwritten.
+
+const TEMPLATE: [(&str, &str); 4] = [
+    // Not named Cargo.toml, cargo would take the template for a crate
+    (
+        "Cargo.toml",
+        include_str!("../templates/day/Cargo.toml.template"),
+    ),
+    ("README.md", include_str!("../templates/day/README.md")),
+    ("src/lib.rs", include_str!("../templates/day/src/lib.rs")),
+    ("src/main.rs", include_str!("../templates/day/src/main.rs")),
+];
+
+// Same width as rustfmt, so the registered solutions stay formatted
+const MAX_WIDTH: usize = 100;
+
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("{{day_padded}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+fn is_day_crate(name: &str) -> bool {
+    name.len() == 5
+        && name.starts_with("day")
+        && name[3..].bytes().all(|byte| byte.is_ascii_digit())
+}
+
+// Inserts the line among the lines of the days, which stay sorted. Without any days the line goes
+// to `fallback`.
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    days: &[(usize, String)],
+    name: &str,
+    line: String,
+    fallback: usize,
+) {
+    let index = days
+        .iter()
+        .find(|(_, day)| day.as_str() > name)
+        .map(|(index, _)| *index)
+        .or_else(|| days.last().map(|(index, _)| index + 1))
+        .unwrap_or(fallback);
+    lines.insert(index, line);
+}
+
+// Adds the crate to the members of the workspace in the root Cargo.toml
+pub fn add_workspace_member(manifest: &str, name: &str) -> Result<String, String> {
+    let mut lines: Vec<String> = manifest.lines().map(String::from).collect();
+    let Some(start) = lines.iter().position(|line| line.trim() == "members = [") else {
+        return Err(String::from("the workspace has no members list"));
+    };
+    let Some(end) = lines[start..].iter().position(|line| line.trim() == "]") else {
+        return Err(String::from(
+            "the members list of the workspace is not closed",
+        ));
+    };
+    let mut days = Vec::new();
+    for (index, line) in lines.iter().enumerate().take(start + end).skip(start + 1) {
+        let member = line.trim().trim_end_matches(',').trim_matches('"');
+        if member == name {
+            return Err(format!("{} is already a member of the workspace", name));
+        }
+        if is_day_crate(member) {
+            days.push((index, member.to_string()));
+        }
+    }
+    insert_sorted(
+        &mut lines,
+        &days,
+        name,
+        format!("    \"{}\",", name),
+        start + 1,
+    );
+    Ok(lines.iter().map(|line| format!("{}\n", line)).collect())
+}
+
+// Adds the crate to the dependencies of the runner in aoc/Cargo.toml
+pub fn add_runner_dependency(manifest: &str, name: &str) -> Result<String, String> {
+    let mut lines: Vec<String> = manifest.lines().map(String::from).collect();
+    let mut days = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        let Some((dependency, _)) = line.split_once(" = ") else {
+            continue;
+        };
+        if dependency == name {
+            return Err(format!("{} is already a dependency of the runner", name));
+        }
+        if is_day_crate(dependency) {
+            days.push((index, dependency.to_string()));
+        }
+    }
+    let line = format!("{} = {{ path = \"../{}\" }}", name, name);
+    let end = lines.len();
+    insert_sorted(&mut lines, &days, name, line, end);
+    Ok(lines.iter().map(|line| format!("{}\n", line)).collect())
+}
+
+fn solution_adapter(day: u8) -> String {
+    let name = format!("day{:02}", day);
+    format!(
+        "pub struct Day{day:02};\n\
+         \n\
+         impl Solution for Day{day:02} {{\n    \
+             fn day(&self) -> u8 {{\n        \
+                 {day}\n    \
+             }}\n\
+         \n    \
+             fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {{\n        \
+                 Ok({name}::solve_part_one(&{name}::parse(input)?).to_string())\n    \
+             }}\n\
+         \n    \
+             fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {{\n        \
+                 Ok({name}::solve_part_two(&{name}::parse(input)?).to_string())\n    \
+             }}\n\
+         }}\n\
+         \n"
+    )
+}
+
+// Adds the adapter of the day to aoc/src/days.rs and the day to the SOLUTIONS of the runner
+pub fn register_solution(days: &str, day: u8) -> Result<String, String> {
+    if days.contains(&format!("pub struct Day{:02};", day)) {
+        return Err(format!("Day {} is already registered in the runner", day));
+    }
+    let Some(start) = days.find("pub static SOLUTIONS") else {
+        return Err(String::from("the runner has no SOLUTIONS"));
+    };
+    let Some(end) = days[start..].find("];").map(|end| start + end + 2) else {
+        return Err(String::from("the SOLUTIONS of the runner are not closed"));
+    };
+    let Some(items_start) = days[start..end].find("= &[").map(|index| start + index + 4) else {
+        return Err(String::from("the SOLUTIONS of the runner are not an array"));
+    };
+
+    let mut solutions: Vec<String> = days[items_start..end - 2]
+        .split(',')
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect();
+    solutions.push(format!("&Day{:02}", day));
+    solutions.sort();
+
+    // Filled like rustfmt fills arrays of short items
+    let mut array = String::from("pub static SOLUTIONS: &[&dyn Solution] = &[\n");
+    let mut line = String::new();
+    for solution in solutions.iter() {
+        if !line.is_empty() && line.len() + solution.len() + 2 > MAX_WIDTH {
+            array.push_str(&line);
+            array.push('\n');
+            line.clear();
+        }
+        if line.is_empty() {
+            line.push_str("   ");
+        }
+        line.push_str(&format!(" {},", solution));
+    }
+    array.push_str(&line);
+    array.push_str("\n];");
+
+    Ok(format!(
+        "{}{}{}{}",
+        &days[..start],
+        solution_adapter(day),
+        array,
+        &days[end..]
+    ))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("Cannot read {}: {}", path.display(), error))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| format!("Cannot create {}: {}", parent.display(), error))?;
+    }
+    fs::write(path, contents).map_err(|error| format!("Cannot write {}: {}", path.display(), error))
+}
+
+// Creates the day in the workspace at `root` and returns the created and changed files. All the
+// changes are prepared before the first file is written, so a failure leaves everything as it was.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day{:02}", day);
+    let crate_dir = root.join(&name);
+    if crate_dir.exists() {
+        return Err(format!(
+            "{} already exists, it is not overwritten",
+            crate_dir.display()
+        ));
+    }
+
+    let workspace_manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let runner_days = root.join("aoc").join("src").join("days.rs");
+    let changes = [
+        (
+            workspace_manifest.clone(),
+            add_workspace_member(&read(&workspace_manifest)?, &name)?,
+        ),
+        (
+            runner_manifest.clone(),
+            add_runner_dependency(&read(&runner_manifest)?, &name)?,
+        ),
+        (
+            runner_days.clone(),
+            register_solution(&read(&runner_days)?, day)?,
+        ),
+    ];
+
+    let mut files = Vec::new();
+    for (file, template) in TEMPLATE.iter() {
+        let path = crate_dir.join(file);
+        write(&path, &fill(template, day))?;
+        files.push(path);
+    }
+    for (path, contents) in changes.iter() {
+        write(path, contents)?;
+        files.push(path.clone());
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn check_registration() {
+        let manifest =
+            "[workspace]\nmembers = [\n    \"day01\",\n    \"day03\",\n    \"aoc\",\n]\n";
+        assert_eq!(
+            add_workspace_member(manifest, "day02").unwrap(),
+            "[workspace]\nmembers = [\n    \"day01\",\n    \"day02\",\n    \"day03\",\n    \"aoc\",\n]\n"
+        );
+        assert!(add_workspace_member(manifest, "day03").is_err());
+
+        let manifest = "[dependencies]\nclap.workspace = true\nday01 = { path = \"../day01\" }\n";
+        assert_eq!(
+            add_runner_dependency(manifest, "day04").unwrap(),
+            "[dependencies]\nclap.workspace = true\nday01 = { path = \"../day01\" }\n\
+             day04 = { path = \"../day04\" }\n"
+        );
+
+        // Day 25 is the last day which could be added
+        let days = register_solution(include_str!("days.rs"), 25).unwrap();
+        assert!(days.contains("pub static SOLUTIONS: &[&dyn Solution] = &[\n"));
+        assert!(days.contains(" &Day25,\n];"));
+        assert!(days.contains("Ok(day25::solve_part_two(&day25::parse(input)?).to_string())"));
+        assert!(register_solution(&days, 25).is_err());
+        assert!(register_solution(include_str!("days.rs"), 7).is_err());
+    }
+
+    #[test]
+    fn check_new_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-test-{}", process::id()));
+        write(&root.join("Cargo.toml"), include_str!("../../Cargo.toml")).unwrap();
+        write(&root.join("aoc/Cargo.toml"), include_str!("../Cargo.toml")).unwrap();
+        write(&root.join("aoc/src/days.rs"), include_str!("days.rs")).unwrap();
+
+        let files = new_day(&root, 25).unwrap();
+        assert_eq!(files.len(), 7);
+        let main = fs::read_to_string(root.join("day25/src/main.rs")).unwrap();
+        assert!(main.contains("use day25::{parse") && main.contains("run_day(25,"));
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("    \"day25\",\n    \"aoc\",\n"));
+
+        // An existing day is never touched
+        fs::write(root.join("day25/src/lib.rs"), "// solved").unwrap();
+        assert!(new_day(&root, 25).unwrap_err().contains("not overwritten"));
+        assert_eq!(
+            fs::read_to_string(root.join("day25/src/lib.rs")).unwrap(),
+            "// solved"
+        );
+        // Neither is a day which is only registered
+        fs::remove_dir_all(root.join("day25")).unwrap();
+        assert!(new_day(&root, 25).unwrap_err().contains("already a member"));
+        assert!(!root.join("day25").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,71 @@
+use crate::days::SOLUTIONS;
+
+// Parses the days requested on the command line. Accepts a single day ("7"), "all",
+// an inclusive range ("3..5" or "3-5") or a comma separated list of any of those ("1,3..5").
+pub fn parse_days(selection: &str) -> Result<Vec<u8>, String> {
+    let available_days: Vec<u8> = SOLUTIONS.iter().map(|solution| solution.day()).collect();
+
+    if selection == "all" {
+        return Ok(available_days);
+    }
+
+    let mut days: Vec<u8> = Vec::new();
+    for part in selection.split(',') {
+        let part = part.trim();
+        let range = part.split_once("..").or_else(|| part.split_once('-'));
+        let (from, to) = match range {
+            Some((from, to)) => (parse_day(from)?, parse_day(to)?),
+            None => {
+                let day = parse_day(part)?;
+                (day, day)
+            }
+        };
+
+        if from > to {
+            return Err(format!("Invalid range of days: {}", part));
+        }
+
+        for day in from..=to {
+            if !available_days.contains(&day) {
+                return Err(format!("Day {} is not solved yet", day));
+            }
+            if !days.contains(&day) {
+                days.push(day);
+            }
+        }
+    }
+
+    days.sort();
+    Ok(days)
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    day.trim()
+        .parse::<u8>()
+        .map_err(|_| format!("Invalid day: {}", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_parse_days() {
+        assert_eq!(parse_days("7"), Ok(vec![7]));
+        assert_eq!(parse_days("3..5"), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days("10-12"), Ok(vec![10, 11, 12]));
+        assert_eq!(parse_days("5,1..2,2"), Ok(vec![1, 2, 5]));
+        assert_eq!(
+            parse_days("all").map(|days| days.len()),
+            Ok(SOLUTIONS.len())
+        );
+    }
+
+    #[test]
+    fn check_parse_days_errors() {
+        let unsolved = SOLUTIONS.len() + 1;
+        assert!(parse_days(&unsolved.to_string()).is_err());
+        assert!(parse_days("5..3").is_err());
+        assert!(parse_days("seven").is_err());
+    }
+}
@@ -0,0 +1,370 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::Input;
+use log::{info, warn};
+use serde_json::json;
+
+use crate::days;
+use crate::output::{self, PartResult, ResultStatus};
+use crate::solution::{Part, SolveError};
+use crate::timeout::solve_with_timeout;
+
+// Local HTTP service for the tools which cannot link the solvers. It speaks just enough HTTP/1.1
+// for curl and the HTTP clients of other languages, every connection gets one response and is
+// closed:
+//
+//   GET  /days                          the days and their parts
+//   POST /days/{day}/parts/{part}       solves the input in the body, answers with the same
+//                                       JSON as `aoc run --format json`
+//
+// Every connection is handled on its own thread. The service listens on the loopback interface
+// by default and never reaches out to the network.
+
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:8025";
+
+// Larger inputs than any puzzle, but small enough that a stray upload does not eat the memory
+const MAX_BODY_LENGTH: usize = 64 * 1024 * 1024;
+
+// Header lines longer than this are not from an HTTP client
+const MAX_LINE_LENGTH: u64 = 8 * 1024;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+    // Methods of the path, sent with 405 Method Not Allowed
+    pub allow: Option<&'static str>,
+}
+
+impl Response {
+    fn json(status: u16, body: serde_json::Value) -> Self {
+        Response {
+            status,
+            body: body.to_string(),
+            allow: None,
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Response::json(status, json!({ "error": message }))
+    }
+
+    fn method_not_allowed(allow: &'static str) -> Self {
+        Response {
+            allow: Some(allow),
+            ..Response::error(405, &format!("only {} is allowed", allow))
+        }
+    }
+
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+             Connection: close\r\n",
+            self.status,
+            reason(self.status),
+            self.body.len()
+        )?;
+        if let Some(allow) = self.allow {
+            write!(writer, "Allow: {}\r\n", allow)?;
+        }
+        write!(writer, "\r\n{}", self.body)?;
+        writer.flush()
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        100 => "Continue",
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Content Too Large",
+        422 => "Unprocessable Content",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
+
+fn read_line(reader: &mut impl BufRead) -> Result<String, Response> {
+    let mut line = String::new();
+    reader
+        .take(MAX_LINE_LENGTH)
+        .read_line(&mut line)
+        .map_err(|error| Response::error(400, &error.to_string()))?;
+    if !line.ends_with('\n') {
+        return Err(Response::error(400, "the request ended early"));
+    }
+    Ok(line.trim_end().to_string())
+}
+
+// Reads the request line, the headers and the body. Clients which wait for a 100 Continue
+// before they send a large body, like curl does, get it.
+pub fn read_request(
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+) -> Result<Request, Response> {
+    let request_line = read_line(reader)?;
+    let mut parts = request_line.split(' ');
+    let (Some(method), Some(target), Some(_version), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(Response::error(400, "expected a request line"));
+    };
+    // The query has no meaning for any of the paths
+    let path = target.split('?').next().unwrap_or(target);
+
+    let mut content_length = 0;
+    let mut expects_continue = false;
+    loop {
+        let header = read_line(reader)?;
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(Response::error(400, "expected a header like Name: value"));
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value
+                .parse()
+                .map_err(|_| Response::error(400, "the Content-Length is not a number"))?;
+        } else if name.eq_ignore_ascii_case("expect") {
+            expects_continue = value.eq_ignore_ascii_case("100-continue");
+        }
+    }
+
+    if content_length > MAX_BODY_LENGTH {
+        return Err(Response::error(
+            413,
+            &format!("the input is larger than {} bytes", MAX_BODY_LENGTH),
+        ));
+    }
+    if expects_continue && content_length > 0 {
+        write!(writer, "HTTP/1.1 100 Continue\r\n\r\n")
+            .and_then(|_| writer.flush())
+            .map_err(|error| Response::error(400, &error.to_string()))?;
+    }
+    let mut body = vec![0; content_length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|_| Response::error(400, "the body is shorter than its Content-Length"))?;
+
+    Ok(Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        body,
+    })
+}
+
+fn list_days() -> Response {
+    let days: Vec<serde_json::Value> = days::SOLUTIONS
+        .iter()
+        .map(|solution| {
+            let parts: Vec<u8> = solution.parts().iter().map(|part| part.number()).collect();
+            json!({ "day": solution.day(), "parts": parts })
+        })
+        .collect();
+    Response::json(200, json!({ "days": days }))
+}
+
+fn solve(day: &str, part: &str, body: &[u8], timeout: Option<Duration>) -> Response {
+    let Some(solution) = day.parse().ok().and_then(days::find) else {
+        return Response::error(404, &format!("day {} is not solved", day));
+    };
+    let Some(part) = part
+        .parse()
+        .ok()
+        .and_then(Part::from_number)
+        .filter(|part| solution.parts().contains(part))
+    else {
+        return Response::error(
+            404,
+            &format!("day {} does not have part {}", solution.day(), part),
+        );
+    };
+    let Ok(text) = str::from_utf8(body) else {
+        return Response::error(400, "the input is not UTF-8");
+    };
+    let input = Input::from_string(text);
+
+    let start = Instant::now();
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+        solve_with_timeout(solution, part, input.text(), timeout, false)
+    }));
+    let elapsed = start.elapsed();
+    let day = solution.day();
+    let (status, result) = match answer {
+        Ok(Ok(answer)) => (200, PartResult::answered(day, part, answer, elapsed)),
+        Ok(Err(error)) => {
+            let (status, result_status) = match error {
+                SolveError::Parse(_) => (422, ResultStatus::Error),
+                SolveError::TimedOut(_) => (503, ResultStatus::Timeout),
+                _ => (500, ResultStatus::Error),
+            };
+            let result = PartResult::failed(day, part, result_status, error.to_string(), elapsed);
+            (status, result)
+        }
+        Err(_) => {
+            let error = String::from("the solver panicked");
+            let result = PartResult::failed(day, part, ResultStatus::Error, error, elapsed);
+            (500, result)
+        }
+    };
+    Response {
+        status,
+        body: output::to_json(&[result]),
+        allow: None,
+    }
+}
+
+// Routes the request, see the top of the file for the paths
+pub fn handle(request: &Request, timeout: Option<Duration>) -> Response {
+    let segments: Vec<&str> = request
+        .path
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["days"]) => list_days(),
+        (_, ["days"]) => Response::method_not_allowed("GET"),
+        ("POST", ["days", day, "parts", part]) => solve(day, part, &request.body, timeout),
+        (_, ["days", _, "parts", _]) => Response::method_not_allowed("POST"),
+        _ => Response::error(404, &format!("{} does not exist", request.path)),
+    }
+}
+
+fn handle_connection(stream: TcpStream, timeout: Option<Duration>) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    let response = match read_request(&mut reader, &mut writer) {
+        Ok(request) => {
+            let response = handle(&request, timeout);
+            info!("{} {} {}", request.method, request.path, response.status);
+            response
+        }
+        Err(response) => response,
+    };
+    response.write_to(&mut writer)
+}
+
+// Answers the requests until the process is stopped
+pub fn serve(listener: TcpListener, timeout: Option<Duration>) {
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                thread::spawn(move || {
+                    if let Err(error) = handle_connection(stream, timeout) {
+                        warn!("Cannot answer a request: {}", error);
+                    }
+                });
+            }
+            Err(error) => warn!("Cannot accept a connection: {}", error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use super::*;
+
+    // Starts the service on a free port of the loopback interface, it runs until the tests end
+    fn start() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || serve(listener, Some(Duration::from_secs(10))));
+        address
+    }
+
+    fn request(address: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (status, body.to_string())
+    }
+
+    #[test]
+    fn check_list_days() {
+        let (status, body) = request(start(), "GET", "/days", "");
+        assert_eq!(status, 200);
+        let days: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let days = days["days"].as_array().unwrap();
+        assert_eq!(days.len(), days::SOLUTIONS.len());
+        assert_eq!(days[0], json!({ "day": 1, "parts": [1, 2] }));
+        assert_eq!(days[11], json!({ "day": 12, "parts": [1] }));
+    }
+
+    #[test]
+    fn check_solve() {
+        let address = start();
+        let input = "L68\r\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+        let (status, body) = request(address, "POST", "/days/1/parts/2", input);
+        assert_eq!(status, 200);
+        let results = output::from_json(&body).unwrap();
+        assert_eq!(results[0].answer.as_deref(), Some("6"));
+        assert_eq!((results[0].day, results[0].part), (1, 2));
+
+        let (status, body) = request(address, "POST", "/days/1/parts/1", "L68\nX30");
+        assert_eq!(status, 422);
+        let results = output::from_json(&body).unwrap();
+        assert_eq!(results[0].status, ResultStatus::Error);
+        assert!(results[0].error.as_ref().unwrap().starts_with("2:1: "));
+    }
+
+    #[test]
+    fn check_routing() {
+        let address = start();
+        let status = |method, path| request(address, method, path, "").0;
+        assert_eq!(status("POST", "/days/25/parts/1"), 404);
+        assert_eq!(status("POST", "/days/12/parts/2"), 404);
+        assert_eq!(status("POST", "/days/1/parts/x"), 404);
+        assert_eq!(status("GET", "/days/1/parts/1"), 405);
+        assert_eq!(status("POST", "/days"), 405);
+        assert_eq!(status("GET", "/"), 404);
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(stream, "nonsense\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+    }
+
+    #[test]
+    fn check_continue() {
+        let mut reader = "POST /days/1/parts/1 HTTP/1.1\r\nExpect: 100-continue\r\n\
+                          content-length: 3\r\n\r\nR50"
+            .as_bytes();
+        let mut written = Vec::new();
+        let request = read_request(&mut reader, &mut written).unwrap();
+        assert_eq!(written, b"HTTP/1.1 100 Continue\r\n\r\n");
+        assert_eq!(request.body, b"R50");
+        assert_eq!(handle(&request, None).status, 200);
+    }
+}
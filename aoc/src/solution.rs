@@ -0,0 +1,109 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use aoc_common::ParseError;
+use aoc_common::cancel::Cancelled;
+use aoc_common::checked::Overflow;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.number(), f)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    MissingPart { day: u8, part: Part },
+    TimedOut(Duration),
+    Overflow(Overflow),
+    // The solver stopped because the runner cancelled it after its timeout
+    Cancelled,
+    // The input is valid, but it has no answer
+    NoAnswer(String),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(error) => write!(f, "{}", error),
+            SolveError::MissingPart { day, part } => {
+                write!(f, "Day {} does not have part {}", day, part)
+            }
+            SolveError::TimedOut(timeout) => {
+                write!(f, "timed out after {}s", timeout.as_secs_f64())
+            }
+            SolveError::Overflow(overflow) => write!(f, "overflow, {}", overflow),
+            SolveError::Cancelled => write!(f, "{}", Cancelled),
+            SolveError::NoAnswer(message) => write!(f, "no answer, {}", message),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        SolveError::Parse(error)
+    }
+}
+
+impl From<Cancelled> for SolveError {
+    fn from(_: Cancelled) -> Self {
+        SolveError::Cancelled
+    }
+}
+
+// Every day is plugged into the runner through this trait. The solvers get the whole content of
+// the input file and return the answer already formatted, because every day returns a different
+// type.
+pub trait Solution: Send + Sync {
+    fn day(&self) -> u8;
+
+    fn parts(&self) -> &'static [Part] {
+        &Part::ALL
+    }
+
+    fn solve_part_one(&self, input: &str) -> Result<String, SolveError>;
+
+    // Not every day has a second part (day 12 only has one puzzle), those days keep this default.
+    fn solve_part_two(&self, _input: &str) -> Result<String, SolveError> {
+        Err(SolveError::MissingPart {
+            day: self.day(),
+            part: Part::Two,
+        })
+    }
+
+    fn solve(&self, part: Part, input: &str) -> Result<String, SolveError> {
+        match part {
+            Part::One => self.solve_part_one(input),
+            Part::Two => self.solve_part_two(input),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::panic;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::cancel::{self, CancelToken};
+use aoc_common::checked::run_checked;
+
+use crate::solution::{Part, Solution, SolveError};
+
+// How long a cancelled solver gets to stop before the runner moves on without it
+const GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+// In checked mode an overflow in the solver replaces its answer, see aoc_common::checked
+fn solve(
+    solution: &dyn Solution,
+    part: Part,
+    input: &str,
+    checked: bool,
+) -> Result<String, SolveError> {
+    if !checked {
+        return solution.solve(part, input);
+    }
+    run_checked(|| solution.solve(part, input))
+        .unwrap_or_else(|overflow| Err(SolveError::Overflow(overflow)))
+}
+
+// Solves the part on its own thread and cancels it when it takes longer than the timeout. The
+// solvers stop cooperatively (see aoc_common::cancel), one which does not check for the
+// cancellation keeps running in the background until the runner exits.
+pub fn solve_with_timeout(
+    solution: &'static dyn Solution,
+    part: Part,
+    input: &str,
+    timeout: Option<Duration>,
+    checked: bool,
+) -> Result<String, SolveError> {
+    let Some(timeout) = timeout else {
+        return solve(solution, part, input, checked);
+    };
+
+    let token = CancelToken::new();
+    let solver_token = token.clone();
+    let input = input.to_string();
+    let (sender, receiver) = mpsc::channel();
+    let solver = thread::spawn(move || {
+        let answer =
+            cancel::run_with_token(&solver_token, || solve(solution, part, &input, checked));
+        let _ = sender.send(answer);
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(answer) => answer,
+        // The solver only drops the sender without an answer when it panics
+        Err(RecvTimeoutError::Disconnected) => match solver.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(()) => unreachable!("the solver always sends its answer"),
+        },
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            let _ = receiver.recv_timeout(GRACE_PERIOD);
+            Err(SolveError::TimedOut(timeout))
+        }
+    }
+}
+
+pub fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    let seconds: f64 = seconds
+        .parse()
+        .map_err(|_| format!("{} is not a number of seconds", seconds))?;
+    if seconds <= 0.0 {
+        return Err(String::from("the timeout has to be longer than zero"));
+    }
+    Duration::try_from_secs_f64(seconds).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::checked;
+
+    use super::*;
+
+    // Counts up until it is cancelled, which is never without a timeout
+    struct Endless;
+
+    impl Solution for Endless {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+            let mut count: u64 = 0;
+            while !cancel::is_cancelled() {
+                count += 1;
+            }
+            Ok(format!("{}{}", input, count))
+        }
+
+        fn solve_part_two(&self, input: &str) -> Result<String, SolveError> {
+            Ok(input.to_string())
+        }
+    }
+
+    // Counts the lines of the input in a byte
+    struct Overflowing;
+
+    impl Solution for Overflowing {
+        fn day(&self) -> u8 {
+            2
+        }
+
+        fn solve_part_one(&self, input: &str) -> Result<String, SolveError> {
+            let lines = input
+                .lines()
+                .fold(0u8, |count, _| checked::add(count, 1, "count of the lines"));
+            Ok(lines.to_string())
+        }
+    }
+
+    #[test]
+    fn check_solve_with_timeout() {
+        let timeout = Some(Duration::from_millis(20));
+        let error = solve_with_timeout(&Endless, Part::One, "", timeout, false).unwrap_err();
+        assert_eq!(error, SolveError::TimedOut(Duration::from_millis(20)));
+        assert_eq!(error.to_string(), "timed out after 0.02s");
+
+        let answer = solve_with_timeout(&Endless, Part::Two, "42", timeout, false).unwrap();
+        assert_eq!(answer, "42");
+    }
+
+    #[test]
+    fn check_checked_mode() {
+        let input = "\n".repeat(300);
+        let answer = solve_with_timeout(&Overflowing, Part::One, &input, None, false);
+        assert_eq!(answer.unwrap(), "44");
+
+        for timeout in [None, Some(Duration::from_secs(10))] {
+            let error =
+                solve_with_timeout(&Overflowing, Part::One, &input, timeout, true).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                "overflow, count of the lines does not fit in u8"
+            );
+        }
+        let answer = solve_with_timeout(&Overflowing, Part::One, "a\nb", None, true);
+        assert_eq!(answer.unwrap(), "2");
+    }
+
+    #[test]
+    fn check_parse_timeout() {
+        assert_eq!(parse_timeout("2.5"), Ok(Duration::from_millis(2500)));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+}
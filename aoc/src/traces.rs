@@ -0,0 +1,159 @@
+use std::fs;
+use std::path::Path;
+
+use aoc_common::recorder::{self, Event, Trace};
+
+use crate::solution::{Part, Solution, SolveError};
+
+// Recording, replaying and comparing the traces of the solvers, see aoc_common::recorder for the
+// format of the files. The days which simulate the puzzle step by step record events (days 1, 4,
+// 7 and 8), the others record an empty trace.
+
+// Solves the part while recording its events, the trace starts with the day, the part and the
+// answer
+pub fn record(solution: &dyn Solution, part: Part, input: &str) -> Result<Trace, SolveError> {
+    let (answer, events) = recorder::recording(|| solution.solve(part, input));
+    Ok(Trace {
+        fields: vec![
+            (String::from("day"), solution.day().to_string()),
+            (String::from("part"), part.to_string()),
+            (String::from("answer"), answer?),
+        ],
+        events,
+    })
+}
+
+pub fn load(path: &Path) -> Result<Trace, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|error| format!("Cannot read {}: {}", path.display(), error))?;
+    Trace::parse(&text).map_err(|error| format!("{} is not a trace: {}", path.display(), error))
+}
+
+pub fn save(trace: &Trace, path: &Path) -> Result<(), String> {
+    fs::write(path, trace.to_string())
+        .map_err(|error| format!("Cannot write {}: {}", path.display(), error))
+}
+
+// Line of a replayed event, the steps are numbered from 1
+pub fn format_step(step: usize, event: &Event) -> String {
+    format!("{:>7} {}", step, event)
+}
+
+// The events of a replay with their steps, optionally only the ones with the name
+pub fn replay<'a>(
+    trace: &'a Trace,
+    name: Option<&'a str>,
+) -> impl Iterator<Item = (usize, &'a Event)> + 'a {
+    trace
+        .events
+        .iter()
+        .enumerate()
+        .map(|(index, event)| (index + 1, event))
+        .filter(move |(_, event)| name.is_none_or(|name| event.name == name))
+}
+
+// First step at which two traces do not agree, an event is None when its trace already ended
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    pub step: usize,
+    pub old: Option<Event>,
+    pub new: Option<Event>,
+}
+
+// Differences of the fields of the traces (like the answer) and the first diverging step
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Comparison {
+    // Key with the old and the new value, a missing field is empty
+    pub fields: Vec<(String, String, String)>,
+    pub divergence: Option<Divergence>,
+}
+
+impl Comparison {
+    pub fn is_same(&self) -> bool {
+        self.fields.is_empty() && self.divergence.is_none()
+    }
+}
+
+pub fn compare(old: &Trace, new: &Trace) -> Comparison {
+    let mut keys: Vec<&str> = old.fields.iter().map(|(key, _)| key.as_str()).collect();
+    for (key, _) in new.fields.iter() {
+        if !keys.contains(&key.as_str()) {
+            keys.push(key);
+        }
+    }
+    let fields = keys
+        .into_iter()
+        .filter(|key| old.field(key) != new.field(key))
+        .map(|key| {
+            (
+                key.to_string(),
+                old.field(key).unwrap_or_default().to_string(),
+                new.field(key).unwrap_or_default().to_string(),
+            )
+        })
+        .collect();
+
+    let steps = old.events.len().max(new.events.len());
+    let divergence = (0..steps)
+        .find(|index| old.events.get(*index) != new.events.get(*index))
+        .map(|index| Divergence {
+            step: index + 1,
+            old: old.events.get(index).cloned(),
+            new: new.events.get(index).cloned(),
+        });
+    Comparison { fields, divergence }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days;
+
+    use super::*;
+
+    const INPUT: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+
+    #[test]
+    fn check_record_and_replay() {
+        let solution = days::find(1).unwrap();
+        let trace = record(solution, Part::Two, INPUT).unwrap();
+        assert_eq!(trace.field("answer"), Some("6"));
+        assert_eq!(trace.events.len(), 10);
+        assert_eq!(
+            format_step(1, &trace.events[0]),
+            "      1 rotate rotation=-68 position=82 zeros=0 clicks=1"
+        );
+        assert_eq!(Trace::parse(&trace.to_string()).unwrap(), trace);
+
+        let zeros: Vec<usize> = replay(&trace, Some("rotate"))
+            .filter(|(_, event)| event.field("position") == Some("0"))
+            .map(|(step, _)| step)
+            .collect();
+        assert_eq!(zeros, [3, 6, 8]);
+        assert_eq!(replay(&trace, Some("split")).count(), 0);
+
+        let error = record(solution, Part::One, "L68\nX30").unwrap_err();
+        assert!(matches!(error, SolveError::Parse(_)));
+    }
+
+    #[test]
+    fn check_compare() {
+        let solution = days::find(1).unwrap();
+        let old = record(solution, Part::Two, INPUT).unwrap();
+        assert!(compare(&old, &old).is_same());
+
+        let new = record(solution, Part::Two, &INPUT.replace("L5\n", "L6\n")).unwrap();
+        let comparison = compare(&old, &new);
+        assert_eq!(
+            comparison.fields,
+            [(String::from("answer"), String::from("6"), String::from("7"))]
+        );
+        let divergence = comparison.divergence.unwrap();
+        assert_eq!(divergence.step, 4);
+        assert_eq!(divergence.old.unwrap().field("position"), Some("95"));
+        assert_eq!(divergence.new.unwrap().field("position"), Some("94"));
+
+        let shorter = record(solution, Part::Two, "L68\nL30").unwrap();
+        let divergence = compare(&shorter, &old).divergence.unwrap();
+        assert_eq!((divergence.step, divergence.old), (3, None));
+    }
+}
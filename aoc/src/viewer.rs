@@ -0,0 +1,594 @@
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use aoc_common::ParseError;
+use aoc_common::grid::{Grid, Position};
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{
+    self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode,
+    enable_raw_mode,
+};
+use crossterm::{execute, queue};
+
+use day01::{Dial, Rotation};
+use day04::Removal;
+use day07::{BeamRow, Cell};
+
+// Terminal viewer which steps through the simulations of days 1, 4 and 7. A simulation computes
+// all of its steps up front from the state the solvers expose, so seeking is as cheap as
+// stepping. Rendering a frame does not need a terminal, which is what the headless mode prints
+// and what the snapshot tests compare.
+
+// What a step of a simulation shows
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    // One line about what happened in the step
+    pub status: String,
+    pub lines: Vec<String>,
+    // Line which has to stay visible when the frame is taller than the screen
+    pub focus: usize,
+}
+
+pub trait Simulation {
+    // Number of steps, the first one is the state before the simulation starts
+    fn steps(&self) -> usize;
+
+    fn frame(&self, step: usize) -> Frame;
+}
+
+// Day which can be viewed, the simulation is built from the puzzle input
+pub struct Viewer {
+    pub day: u8,
+    load: fn(&str) -> Result<Box<dyn Simulation>, ParseError>,
+}
+
+impl Viewer {
+    pub fn load(&self, input: &str) -> Result<Box<dyn Simulation>, ParseError> {
+        (self.load)(input)
+    }
+}
+
+pub static VIEWERS: [Viewer; 3] = [
+    Viewer {
+        day: 1,
+        load: load_day01,
+    },
+    Viewer {
+        day: 4,
+        load: load_day04,
+    },
+    Viewer {
+        day: 7,
+        load: load_day07,
+    },
+];
+
+pub fn find(day: u8) -> Option<&'static Viewer> {
+    VIEWERS.iter().find(|viewer| viewer.day == day)
+}
+
+// The dial after every rotation
+struct DialSimulation {
+    rotations: Vec<Rotation>,
+    dials: Vec<Dial>,
+}
+
+fn load_day01(input: &str) -> Result<Box<dyn Simulation>, ParseError> {
+    let rotations = day01::parse(input)?;
+    let mut dial = Dial::default();
+    let mut dials = vec![dial];
+    for rotation in rotations.iter() {
+        dial.rotate(*rotation);
+        dials.push(dial);
+    }
+    Ok(Box::new(DialSimulation { rotations, dials }))
+}
+
+fn format_rotation(rotation: Rotation) -> String {
+    match rotation < 0 {
+        true => format!("L{}", -rotation),
+        false => format!("R{}", rotation),
+    }
+}
+
+// The 100 positions of the dial in two rows of 50, with a ^ under the position it points at
+fn dial_lines(position: i32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for first in [0, 50] {
+        let labels: String = (first..first + 50)
+            .step_by(10)
+            .map(|label| format!("{:<10}", label))
+            .collect();
+        let ticks: String = (first..first + 50)
+            .map(|tick| if tick % 10 == 0 { '|' } else { '.' })
+            .collect();
+        let marker = match (first..first + 50).contains(&position) {
+            true => format!("{}^", " ".repeat((position - first) as usize)),
+            false => String::new(),
+        };
+        lines.extend([labels, ticks, marker]);
+    }
+    lines
+}
+
+impl Simulation for DialSimulation {
+    fn steps(&self) -> usize {
+        self.dials.len()
+    }
+
+    fn frame(&self, step: usize) -> Frame {
+        let dial = self.dials[step];
+        let status = match step {
+            0 => format!("The dial starts at {}", dial.position()),
+            _ => format!(
+                "Rotation {} of {}: {}, the dial points at {}",
+                step,
+                self.rotations.len(),
+                format_rotation(self.rotations[step - 1]),
+                dial.position()
+            ),
+        };
+
+        let mut lines = dial_lines(dial.position());
+        lines.push(String::new());
+        lines.push(format!("Stops at 0:  {}", dial.zero_count()));
+        lines.push(format!("Clicks at 0: {}", dial.clicks_count()));
+        if step > 0 {
+            lines.push(String::new());
+            lines.push(String::from("Last rotations:"));
+            for earlier in (step.saturating_sub(5)..step).rev() {
+                lines.push(format!(
+                    "  {:<5} {:>2} -> {}",
+                    format_rotation(self.rotations[earlier]),
+                    self.dials[earlier].position(),
+                    self.dials[earlier + 1].position()
+                ));
+            }
+        }
+        Frame {
+            status,
+            lines,
+            focus: 0,
+        }
+    }
+}
+
+// The map after every wave of removed rolls
+struct RemovalSimulation {
+    map: day04::Map,
+    waves: Vec<Vec<Position>>,
+}
+
+fn load_day04(input: &str) -> Result<Box<dyn Simulation>, ParseError> {
+    let map = day04::parse(input)?;
+    let waves = Removal::new(map.clone()).collect();
+    Ok(Box::new(RemovalSimulation { map, waves }))
+}
+
+impl Simulation for RemovalSimulation {
+    fn steps(&self) -> usize {
+        self.waves.len() + 1
+    }
+
+    // The rolls removed in earlier waves are gone, the ones of this wave are marked with x
+    fn frame(&self, step: usize) -> Frame {
+        let mut map = self.map.map(|_, is_roll| if *is_roll { '@' } else { '.' });
+        for (wave, removed_rolls) in self.waves.iter().take(step).enumerate() {
+            for position in removed_rolls.iter() {
+                map[*position] = if wave + 1 == step { 'x' } else { '.' };
+            }
+        }
+
+        let status = match step {
+            0 => format!(
+                "The map has {} rolls",
+                self.map.cells().filter(|(_, is_roll)| **is_roll).count()
+            ),
+            _ => format!(
+                "Wave {} of {}: removed {} rolls, {} in total",
+                step,
+                self.waves.len(),
+                self.waves[step - 1].len(),
+                self.waves.iter().take(step).map(Vec::len).sum::<usize>()
+            ),
+        };
+        Frame {
+            status,
+            lines: map.rows().map(|row| row.iter().collect()).collect(),
+            focus: 0,
+        }
+    }
+}
+
+// The beams after every row of the manifold
+struct BeamSimulation {
+    grid: Grid<Cell>,
+    rows: Vec<BeamRow>,
+}
+
+fn load_day07(input: &str) -> Result<Box<dyn Simulation>, ParseError> {
+    let manifold = day07::parse(input)?;
+    let rows = manifold.beam_rows().collect();
+    Ok(Box::new(BeamSimulation {
+        grid: manifold.grid().clone(),
+        rows,
+    }))
+}
+
+impl Simulation for BeamSimulation {
+    fn steps(&self) -> usize {
+        self.rows.len() + 1
+    }
+
+    // The rows the beams went through show them as |, the rows below are still empty
+    fn frame(&self, step: usize) -> Frame {
+        let mut lines: Vec<String> = self
+            .grid
+            .rows()
+            .map(|row| row.iter().map(|cell| cell_symbol(*cell)).collect())
+            .collect();
+        for beam_row in self.rows.iter().take(step) {
+            lines[beam_row.row] = self
+                .grid
+                .row(beam_row.row)
+                .iter()
+                .zip(beam_row.beams.iter())
+                .map(|(cell, beam)| match (cell, beam) {
+                    (Cell::Empty, true) => '|',
+                    (cell, _) => cell_symbol(*cell),
+                })
+                .collect();
+        }
+
+        let status = match step {
+            0 => String::from("The beam enters the manifold at S"),
+            _ => {
+                let beam_row = &self.rows[step - 1];
+                format!(
+                    "Row {} of {}: {} splits, {} in total, {} beams",
+                    beam_row.row,
+                    self.rows.len(),
+                    beam_row.splits.len(),
+                    self.rows
+                        .iter()
+                        .take(step)
+                        .map(|row| row.splits.len())
+                        .sum::<usize>(),
+                    beam_row.beams.iter().filter(|beam| **beam).count()
+                )
+            }
+        };
+        Frame {
+            status,
+            lines,
+            focus: step,
+        }
+    }
+}
+
+fn cell_symbol(cell: Cell) -> char {
+    day07::SYMBOLS
+        .iter()
+        .find(|(_, symbol_cell)| *symbol_cell == cell)
+        .map_or('?', |(symbol, _)| *symbol)
+}
+
+// What the keys of the viewer do
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    TogglePlay,
+    Forward,
+    Back,
+    JumpForward,
+    JumpBack,
+    Start,
+    End,
+    Faster,
+    Slower,
+    Quit,
+}
+
+pub const MIN_SPEED: u32 = 1;
+pub const MAX_SPEED: u32 = 1024;
+
+// Which step the viewer shows and whether it plays the simulation, the speed is in steps per
+// second and doubles or halves
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Player {
+    step: usize,
+    steps: usize,
+    playing: bool,
+    speed: u32,
+}
+
+impl Player {
+    pub fn new(steps: usize, speed: u32) -> Self {
+        Player {
+            step: 0,
+            steps: steps.max(1),
+            playing: false,
+            speed: speed.clamp(MIN_SPEED, MAX_SPEED),
+        }
+    }
+
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    pub fn speed(&self) -> u32 {
+        self.speed
+    }
+
+    // Time between two steps while playing
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(1) / self.speed
+    }
+
+    pub fn seek(&mut self, step: usize) {
+        self.step = step.min(self.steps - 1);
+    }
+
+    pub fn apply(&mut self, command: Command) {
+        // Jumps go a tenth of the simulation, at least one step
+        let jump = (self.steps / 10).max(1);
+        match command {
+            Command::TogglePlay => {
+                // Playing again from the end starts over
+                if !self.playing && self.step == self.steps - 1 {
+                    self.step = 0;
+                }
+                self.playing = !self.playing;
+            }
+            Command::Forward => self.seek(self.step + 1),
+            Command::Back => self.seek(self.step.saturating_sub(1)),
+            Command::JumpForward => self.seek(self.step + jump),
+            Command::JumpBack => self.seek(self.step.saturating_sub(jump)),
+            Command::Start => self.seek(0),
+            Command::End => self.seek(self.steps - 1),
+            Command::Faster => self.speed = (self.speed * 2).min(MAX_SPEED),
+            Command::Slower => self.speed = (self.speed / 2).max(MIN_SPEED),
+            Command::Quit => self.playing = false,
+        }
+    }
+
+    // Moves to the next step while playing, the player pauses at the last step
+    pub fn tick(&mut self) {
+        if self.playing {
+            self.seek(self.step + 1);
+            self.playing = self.step < self.steps - 1;
+        }
+    }
+}
+
+const HELP: &str = "space play/pause  left/right step  pgup/pgdn jump  home/end  +/- speed  q quit";
+
+fn crop(line: &str, width: usize) -> String {
+    line.chars()
+        .take(width)
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+// Renders the step of the player as text of at most `width` columns and `height` lines: a
+// header, the status of the step, as many lines of the frame as fit around its focus and the
+// keys at the bottom
+pub fn render(
+    title: &str,
+    simulation: &dyn Simulation,
+    player: &Player,
+    size: (usize, usize),
+) -> String {
+    let (width, height) = size;
+    let frame = simulation.frame(player.step());
+    let header = format!(
+        "{}  step {}/{}  {}  {} steps/s",
+        title,
+        player.step(),
+        simulation.steps() - 1,
+        if player.is_playing() {
+            "playing"
+        } else {
+            "paused"
+        },
+        player.speed()
+    );
+
+    let visible = height.saturating_sub(4);
+    let first = frame
+        .focus
+        .saturating_sub(visible / 2)
+        .min(frame.lines.len().saturating_sub(visible));
+    let mut lines = vec![header, frame.status, String::new()];
+    lines.extend(frame.lines.iter().skip(first).take(visible).cloned());
+    lines.push(String::from(HELP));
+
+    let lines: Vec<String> = lines
+        .iter()
+        .take(height)
+        .map(|line| crop(line, width))
+        .collect();
+    lines.join("\n")
+}
+
+fn command(key: KeyEvent) -> Option<Command> {
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Some(Command::Quit),
+        KeyCode::Char(' ') | KeyCode::Char('p') => Some(Command::TogglePlay),
+        KeyCode::Right | KeyCode::Char('l') => Some(Command::Forward),
+        KeyCode::Left | KeyCode::Char('h') => Some(Command::Back),
+        KeyCode::PageDown | KeyCode::Char('L') => Some(Command::JumpForward),
+        KeyCode::PageUp | KeyCode::Char('H') => Some(Command::JumpBack),
+        KeyCode::Home | KeyCode::Char('g') => Some(Command::Start),
+        KeyCode::End | KeyCode::Char('G') => Some(Command::End),
+        KeyCode::Char('+') | KeyCode::Char('=') => Some(Command::Faster),
+        KeyCode::Char('-') => Some(Command::Slower),
+        KeyCode::Char('q') | KeyCode::Esc => Some(Command::Quit),
+        _ => None,
+    }
+}
+
+// Puts the terminal back the way it was, also when the viewer panics
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> io::Result<Self> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = disable_raw_mode();
+    }
+}
+
+fn draw(title: &str, simulation: &dyn Simulation, player: &Player) -> io::Result<()> {
+    let (columns, rows) = terminal::size()?;
+    let text = render(title, simulation, player, (columns as usize, rows as usize));
+    let mut stdout = io::stdout().lock();
+    queue!(stdout, MoveTo(0, 0), Clear(ClearType::All))?;
+    // Raw mode does not return the cursor to the start of the line
+    write!(stdout, "{}", text.replace('\n', "\r\n"))?;
+    stdout.flush()
+}
+
+// Shows the simulation in the terminal until q is pressed
+pub fn run(title: &str, simulation: &dyn Simulation, speed: u32) -> io::Result<()> {
+    let _guard = TerminalGuard::enter()?;
+    let mut player = Player::new(simulation.steps(), speed);
+    let mut last_tick = Instant::now();
+    loop {
+        draw(title, simulation, &player)?;
+        // Without playing there is nothing to do until a key is pressed
+        let timeout = match player.is_playing() {
+            true => player.interval().saturating_sub(last_tick.elapsed()),
+            false => Duration::from_secs(60),
+        };
+        if !event::poll(timeout)? {
+            player.tick();
+            last_tick = Instant::now();
+            continue;
+        }
+        match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => match command(key) {
+                Some(Command::Quit) => return Ok(()),
+                Some(command) => {
+                    if command == Command::TogglePlay {
+                        last_tick = Instant::now();
+                    }
+                    player.apply(command);
+                }
+                None => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+
+    use aoc_common::examples::parse_examples;
+
+    use super::*;
+
+    // First example of the README of the day
+    fn example(readme: &str) -> String {
+        parse_examples(readme).unwrap().remove(0).input
+    }
+
+    // Every frame of the simulation in the headless rendering, compared with the snapshot in
+    // aoc/snapshots. AOC_UPDATE_SNAPSHOTS=1 rewrites the snapshots.
+    fn check_snapshot(day: u8, input: &str) {
+        let simulation = find(day).unwrap().load(input).unwrap();
+        let mut player = Player::new(simulation.steps(), 4);
+        let mut frames = Vec::new();
+        for _ in 0..simulation.steps() {
+            frames.push(render(
+                &format!("Day {:02}", day),
+                simulation.as_ref(),
+                &player,
+                (80, 24),
+            ));
+            player.apply(Command::Forward);
+        }
+        let rendered = frames.join("\n\n") + "\n";
+
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("snapshots")
+            .join(format!("view-day{:02}.txt", day));
+        if env::var_os("AOC_UPDATE_SNAPSHOTS").is_some() {
+            fs::write(&path, &rendered).unwrap();
+        }
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            rendered,
+            "{} is out of date, rerun the tests with AOC_UPDATE_SNAPSHOTS=1",
+            path.display()
+        );
+    }
+
+    #[test]
+    fn check_snapshots() {
+        check_snapshot(1, &example(include_str!("../../day01/README.md")));
+        check_snapshot(4, &example(include_str!("../../day04/README.md")));
+        check_snapshot(7, &example(include_str!("../../day07/README.md")));
+    }
+
+    #[test]
+    fn check_player() {
+        let mut player = Player::new(25, 4);
+        player.apply(Command::JumpForward);
+        assert_eq!(player.step(), 2);
+        player.apply(Command::Back);
+        player.apply(Command::Back);
+        player.apply(Command::Back);
+        assert_eq!(player.step(), 0);
+        player.apply(Command::End);
+        assert_eq!(player.step(), 24);
+
+        // Playing from the end starts over and pauses at the end again
+        player.apply(Command::TogglePlay);
+        assert_eq!((player.step(), player.is_playing()), (0, true));
+        for _ in 0..30 {
+            player.tick();
+        }
+        assert_eq!((player.step(), player.is_playing()), (24, false));
+
+        player.apply(Command::Faster);
+        assert_eq!(player.interval(), Duration::from_millis(125));
+        for _ in 0..20 {
+            player.apply(Command::Slower);
+        }
+        assert_eq!(player.speed(), MIN_SPEED);
+    }
+
+    #[test]
+    fn check_render_fits_the_size() {
+        let simulation = find(7)
+            .unwrap()
+            .load(&example(include_str!("../../day07/README.md")));
+        let simulation = simulation.unwrap();
+        let mut player = Player::new(simulation.steps(), 4);
+        player.apply(Command::End);
+        let rendered = render("Day 07", simulation.as_ref(), &player, (12, 8));
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 8);
+        assert!(lines.iter().all(|line| line.chars().count() <= 12));
+        // The frame follows the last row of the beams
+        assert_eq!(lines[6], "|.|.|.|.|.||");
+        assert!(find(2).is_none());
+    }
+}
@@ -0,0 +1,231 @@
+use std::env;
+use std::ffi::OsString;
+use std::path::{self, Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use aoc_common::InputSource;
+use aoc_common::input::workspace_root;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use crate::bench::format_duration;
+use crate::output::{self, PartResult};
+
+// Watch mode: the tests and both parts of a day run again whenever its sources or its input
+// change. The runner cannot load the changed code of a day, so every run goes through cargo,
+// which rebuilds the day: cargo test for the tests and this runner with --format json for the
+// answers, which are compared with the answers of the previous run.
+
+// Changes which come in quick succession, like an editor saving several files, run only once
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+type Events = Receiver<notify::Result<Event>>;
+
+fn cargo() -> Command {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let mut command = Command::new(cargo);
+    command.current_dir(workspace_root());
+    command
+}
+
+fn summary(result: &PartResult) -> String {
+    match (&result.answer, &result.error) {
+        (Some(answer), _) => answer.clone(),
+        (None, error) => format!(
+            "{} ({})",
+            result.status.name(),
+            error.as_deref().unwrap_or_default()
+        ),
+    }
+}
+
+// One line per part, a part whose answer or error changed since the previous run shows the
+// previous one as well
+pub fn describe_changes(previous: &[PartResult], current: &[PartResult]) -> Vec<String> {
+    current
+        .iter()
+        .map(|result| {
+            let now = summary(result);
+            match previous.iter().find(|before| before.part == result.part) {
+                Some(before) if summary(before) != now => {
+                    format!("Part {}: {} (was {})", result.part, now, summary(before))
+                }
+                _ => format!("Part {}: {}", result.part, now),
+            }
+        })
+        .collect()
+}
+
+fn run_tests(day: u8) {
+    let start = Instant::now();
+    let output = cargo()
+        .args(["test", "--quiet", "--package", &format!("day{:02}", day)])
+        .output();
+    let elapsed = format_duration(start.elapsed().as_nanos() as u64);
+    match output {
+        Ok(output) if output.status.success() => println!("Tests: passed in {}", elapsed),
+        Ok(output) => {
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("Tests: FAILED in {}", elapsed);
+        }
+        Err(error) => println!("Tests: cannot run cargo: {}", error),
+    }
+}
+
+// The runner exits with an error when a part fails, but the results are printed all the same.
+// Without any results it did not even build, and its errors are shown instead.
+fn run_parts(day: u8, input: Option<&Path>) -> Option<Vec<PartResult>> {
+    let mut command = cargo();
+    command.args(["run", "--quiet", "--release", "--bin", "aoc", "--", "run"]);
+    command.args([&day.to_string(), "--format", "json"]);
+    if let Some(input) = input {
+        command.arg("--input").arg(input);
+    }
+    let output = match command.output() {
+        Ok(output) => output,
+        Err(error) => {
+            println!("Cannot run cargo: {}", error);
+            return None;
+        }
+    };
+    match output::from_json(&String::from_utf8_lossy(&output.stdout)) {
+        Ok(results) => Some(results),
+        Err(_) => {
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("The solutions did not run");
+            None
+        }
+    }
+}
+
+// Waits for the next change of the watched paths and returns every path changed until things
+// settle down
+fn wait_for_changes(events: &Events, watched: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+    let relevant = |event: notify::Result<Event>| -> Vec<PathBuf> {
+        match event {
+            Ok(event) if !matches!(event.kind, EventKind::Access(_)) => event
+                .paths
+                .into_iter()
+                .filter(|path| watched.iter().any(|watched| path.starts_with(watched)))
+                .collect(),
+            _ => Vec::new(),
+        }
+    };
+
+    let mut changed = Vec::new();
+    while changed.is_empty() {
+        let event = events
+            .recv()
+            .map_err(|_| String::from("the file watcher stopped"))?;
+        changed.extend(relevant(event));
+    }
+    loop {
+        match events.recv_timeout(SETTLE_TIME) {
+            Ok(event) => changed.extend(relevant(event)),
+            Err(RecvTimeoutError::Timeout) => break,
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(String::from("the file watcher stopped"));
+            }
+        }
+    }
+    changed.sort();
+    changed.dedup();
+    Ok(changed)
+}
+
+// Runs the day now and after every change of its sources or its input, until it is interrupted
+pub fn watch(day: u8, input: Option<&Path>, tests: bool) -> Result<(), String> {
+    let root = workspace_root();
+    let sources = root.join(format!("day{:02}", day)).join("src");
+    if !sources.is_dir() {
+        return Err(format!("{} does not exist", sources.display()));
+    }
+    let input_path = match InputSource::resolve(day, input)? {
+        InputSource::File(path) => path::absolute(&path).map_err(|error| error.to_string())?,
+        InputSource::Stdin => return Err(String::from("watch needs an input file")),
+    };
+    // Editors often replace the file instead of writing it, so the folder of the input is watched
+    let Some(input_dir) = input_path.parent().filter(|parent| parent.is_dir()) else {
+        return Err(format!("{} does not exist", input_path.display()));
+    };
+
+    let (sender, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(|error| error.to_string())?;
+    watcher
+        .watch(&sources, RecursiveMode::Recursive)
+        .and_then(|_| watcher.watch(input_dir, RecursiveMode::NonRecursive))
+        .map_err(|error| error.to_string())?;
+
+    let watched = [sources.clone(), input_path.clone()];
+    let explicit_input = input.map(|_| input_path.as_path());
+    let mut previous = Vec::new();
+    loop {
+        if tests {
+            run_tests(day);
+        }
+        if let Some(results) = run_parts(day, explicit_input) {
+            for line in describe_changes(&previous, &results) {
+                println!("{}", line);
+            }
+            previous = results;
+        }
+        println!(
+            "Watching {} and {}, press Ctrl+C to stop",
+            sources.strip_prefix(&root).unwrap_or(&sources).display(),
+            input_path
+                .strip_prefix(&root)
+                .unwrap_or(&input_path)
+                .display()
+        );
+
+        let changed = wait_for_changes(&events, &watched)?;
+        let changed: Vec<String> = changed
+            .iter()
+            .map(|path| {
+                path.strip_prefix(&root)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        println!("\nChanged {}", changed.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::ResultStatus;
+    use crate::solution::Part;
+
+    #[test]
+    fn check_describe_changes() {
+        let answer = |part, answer: &str| {
+            PartResult::answered(7, part, String::from(answer), Duration::ZERO)
+        };
+        let previous = vec![answer(Part::One, "1507"), answer(Part::Two, "40")];
+        let current = vec![
+            answer(Part::One, "1507"),
+            PartResult::failed(
+                7,
+                Part::Two,
+                ResultStatus::Timeout,
+                String::from("timed out after 1s"),
+                Duration::from_secs(1),
+            ),
+        ];
+        assert_eq!(
+            describe_changes(&previous, &current),
+            [
+                "Part 1: 1507",
+                "Part 2: timeout (timed out after 1s) (was 40)"
+            ]
+        );
+        assert_eq!(
+            describe_changes(&[], &previous),
+            ["Part 1: 1507", "Part 2: 40"]
+        );
+    }
+}
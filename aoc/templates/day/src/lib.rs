@@ -0,0 +1,45 @@
+//! Day {{day}}: describe the puzzle and the shape of its input here.
+
+use aoc_common::ParseError;
+use aoc_common::error::parse_lines;
+
+/// A single line of the input, replace it with the model of the puzzle.
+pub type Line = String;
+
+/// Parses the whole input, one line at a time.
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    parse_lines(input, |line| Ok(line.to_string()))
+}
+
+/// Solves the first part of the puzzle.
+pub fn solve_part_one(lines: &[Line]) -> usize {
+    lines.len()
+}
+
+/// Solves the second part of the puzzle.
+pub fn solve_part_two(_lines: &[Line]) -> usize {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_solution_part_two() {
+        check_examples(README, 2, |example| {
+            solve_part_two(&parse(&example.input).unwrap())
+        });
+    }
+}
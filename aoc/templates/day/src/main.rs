@@ -0,0 +1,13 @@
+use aoc_common::run_day;
+use day{{day_padded}}::{parse, solve_part_one, solve_part_two};
+
+fn main() {
+    run_day({{day}}, |input| {
+        let lines = parse(input.text())?;
+
+        println!("Result for part 1 is: {}", solve_part_one(&lines));
+        println!("Result for part 2 is: {}", solve_part_two(&lines));
+
+        Ok(())
+    });
+}
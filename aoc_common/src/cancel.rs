@@ -0,0 +1,106 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Cooperative cancellation of the solvers. The runner hands a token to the thread which runs a
+// solver and cancels it when the solver runs out of time. The long running loops of the solvers
+// ask is_cancelled() (or check()) now and then and stop early with Cancelled instead of an answer.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        CancelToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+// Error of a solver which stopped because it was cancelled, it has no answer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cancelled before it finished")
+    }
+}
+
+impl Error for Cancelled {}
+
+thread_local! {
+    static CURRENT_TOKEN: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+// Puts the previous token back when it is dropped, also when the solver panics
+struct RestoreToken(Option<CancelToken>);
+
+impl Drop for RestoreToken {
+    fn drop(&mut self) {
+        CURRENT_TOKEN.set(self.0.take());
+    }
+}
+
+// Runs the function with the token as the token of the current thread
+pub fn run_with_token<R>(token: &CancelToken, run: impl FnOnce() -> R) -> R {
+    let _restore = RestoreToken(CURRENT_TOKEN.replace(Some(token.clone())));
+    run()
+}
+
+// Whether the solver running on this thread should stop, never true without a token
+pub fn is_cancelled() -> bool {
+    CURRENT_TOKEN.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(CancelToken::is_cancelled)
+    })
+}
+
+// Err(Cancelled) when the solver should stop, for returning early with ?
+pub fn check() -> Result<(), Cancelled> {
+    match is_cancelled() {
+        true => Err(Cancelled),
+        false => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use super::*;
+
+    #[test]
+    fn check_cancellation() {
+        assert!(!is_cancelled());
+
+        let token = CancelToken::new();
+        run_with_token(&token, || {
+            assert!(!is_cancelled());
+            token.cancel();
+            assert!(is_cancelled());
+        });
+        assert!(!is_cancelled());
+
+        // A panicking solver does not leave its token on the thread
+        let result = panic::catch_unwind(|| run_with_token(&token, || panic!("solver failed")));
+        assert!(result.is_err());
+        assert!(!is_cancelled());
+        assert_eq!(check(), Ok(()));
+
+        let other_token = token.clone();
+        let cancelled = std::thread::spawn(move || run_with_token(&other_token, is_cancelled));
+        assert!(cancelled.join().unwrap());
+    }
+}
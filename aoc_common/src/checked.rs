@@ -0,0 +1,167 @@
+use std::any;
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+
+// Overflow checks of the arithmetic in the solvers. Release builds let the fixed width integers
+// wrap around silently, so a large input can give a wrong answer without any error. The solvers
+// do the sums and products which could overflow with add, mul and sum. Those always return the
+// wrapped result like a release build, but on a thread which runs in checked mode (see
+// run_checked) the first overflow is remembered and reported instead of the answer. Answers
+// which really need more than 64 bits use aoc_common::wide instead.
+
+// The step of a solver which overflowed, like "sum of the joltages"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overflow {
+    pub step: &'static str,
+    pub integer: &'static str,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} does not fit in {}", self.step, self.integer)
+    }
+}
+
+impl Error for Overflow {}
+
+pub trait FixedWidth: Copy {
+    const ZERO: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_mul(self, other: Self) -> Self;
+}
+
+macro_rules! fixed_width {
+    ($($integer:ty),*) => {
+        $(
+            impl FixedWidth for $integer {
+                const ZERO: Self = 0;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$integer>::checked_add(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$integer>::checked_mul(self, other)
+                }
+
+                fn wrapping_add(self, other: Self) -> Self {
+                    <$integer>::wrapping_add(self, other)
+                }
+
+                fn wrapping_mul(self, other: Self) -> Self {
+                    <$integer>::wrapping_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+fixed_width!(u8, u32, u64, usize, i32, i64);
+
+// None outside of checked mode, otherwise the first overflow so far
+thread_local! {
+    static CHECKED: RefCell<Option<Option<Overflow>>> = const { RefCell::new(None) };
+}
+
+// Puts the previous mode back when it is dropped, also when the solver panics
+struct RestoreMode(Option<Option<Overflow>>);
+
+impl Drop for RestoreMode {
+    fn drop(&mut self) {
+        CHECKED.set(self.0.take());
+    }
+}
+
+// Runs the function in checked mode on the current thread, an overflow on the way replaces its
+// result
+pub fn run_checked<R>(run: impl FnOnce() -> R) -> Result<R, Overflow> {
+    let restore = RestoreMode(CHECKED.replace(Some(None)));
+    let result = run();
+    let overflow = CHECKED.take().flatten();
+    drop(restore);
+    match overflow {
+        Some(overflow) => Err(overflow),
+        None => Ok(result),
+    }
+}
+
+pub fn is_checked() -> bool {
+    CHECKED.with_borrow(Option::is_some)
+}
+
+fn overflowed<T>(step: &'static str) {
+    CHECKED.with_borrow_mut(|checked| {
+        if let Some(first @ None) = checked {
+            *first = Some(Overflow {
+                step,
+                integer: any::type_name::<T>(),
+            });
+        }
+    });
+}
+
+pub fn add<T: FixedWidth>(left: T, right: T, step: &'static str) -> T {
+    left.checked_add(right).unwrap_or_else(|| {
+        overflowed::<T>(step);
+        left.wrapping_add(right)
+    })
+}
+
+pub fn mul<T: FixedWidth>(left: T, right: T, step: &'static str) -> T {
+    left.checked_mul(right).unwrap_or_else(|| {
+        overflowed::<T>(step);
+        left.wrapping_mul(right)
+    })
+}
+
+pub fn sum<T, I>(values: I, step: &'static str) -> T
+where
+    T: FixedWidth,
+    I: IntoIterator<Item = T>,
+{
+    values
+        .into_iter()
+        .fold(T::ZERO, |sum, value| add(sum, value, step))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_overflow_is_reported() {
+        // Outside of checked mode the result wraps like in a release build
+        assert_eq!(add(u32::MAX, 2, "counting"), 1);
+        assert!(!is_checked());
+
+        let result = run_checked(|| {
+            assert!(is_checked());
+            let product = mul(u64::MAX / 2, 3, "product");
+            sum([i32::MAX, 1, 1], "sum");
+            product
+        });
+        assert_eq!(
+            result,
+            Err(Overflow {
+                step: "product",
+                integer: "u64"
+            })
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "product does not fit in u64"
+        );
+        assert!(!is_checked());
+
+        assert_eq!(run_checked(|| sum([1u64, 2, 3], "sum")), Ok(6));
+
+        // A panicking solver does not leave the thread in checked mode
+        let result = std::panic::catch_unwind(|| run_checked(|| panic!("solver failed")));
+        assert!(result.is_err());
+        assert!(!is_checked());
+    }
+}
@@ -0,0 +1,201 @@
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+use crate::error::{AocError, ParseError};
+use crate::input::{INPUT_DIR_VARIABLE, Input, InputLines, InputSource};
+use crate::logging::{self, LOG_VARIABLE};
+use crate::profile::{self, DEFAULT_YEAR, PROFILE_VARIABLE, YEAR_VARIABLE};
+
+// Arguments accepted by the binaries of the days, they are simple enough to not need clap
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DayArgs {
+    pub input: Option<PathBuf>,
+    pub profile: Option<String>,
+    pub year: Option<u16>,
+    pub verbosity: u8,
+}
+
+impl DayArgs {
+    pub fn parse_from<I>(arguments: I) -> Result<Self, AocError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = DayArgs::default();
+        let mut arguments = arguments.into_iter();
+        while let Some(argument) = arguments.next() {
+            if let Some(path) = argument.strip_prefix("--input=") {
+                args.input = Some(PathBuf::from(path));
+                continue;
+            }
+            if let Some(profile) = argument.strip_prefix("--profile=") {
+                profile::check_profile(profile).map_err(AocError::Usage)?;
+                args.profile = Some(String::from(profile));
+                continue;
+            }
+            if let Some(year) = argument.strip_prefix("--year=") {
+                args.year = Some(profile::parse_year(year).map_err(AocError::Usage)?);
+                continue;
+            }
+            // -v, -vv and -vvv the same way as clap counts them in the runner
+            if argument.len() > 1
+                && argument
+                    .strip_prefix('-')
+                    .is_some_and(|flags| flags.chars().all(|flag| flag == 'v'))
+            {
+                args.verbosity += (argument.len() - 1) as u8;
+                continue;
+            }
+            match argument.as_str() {
+                "--verbose" => args.verbosity += 1,
+                "-i" | "--input" => {
+                    let Some(path) = arguments.next() else {
+                        return Err(AocError::Usage(format!("{} expects a path", argument)));
+                    };
+                    args.input = Some(PathBuf::from(path));
+                }
+                "--profile" => {
+                    let Some(profile) = arguments.next() else {
+                        return Err(AocError::Usage(format!("{} expects a name", argument)));
+                    };
+                    profile::check_profile(&profile).map_err(AocError::Usage)?;
+                    args.profile = Some(profile);
+                }
+                "--year" => {
+                    let Some(year) = arguments.next() else {
+                        return Err(AocError::Usage(format!("{} expects a year", argument)));
+                    };
+                    args.year = Some(profile::parse_year(&year).map_err(AocError::Usage)?);
+                }
+                _ => {
+                    return Err(AocError::Usage(format!("Unexpected argument {}", argument)));
+                }
+            }
+        }
+        Ok(args)
+    }
+}
+
+fn usage(day: u8) -> String {
+    format!(
+        "Usage: day{:02} [-i|--input PATH] [--profile NAME] [--year YEAR] [-v|-vv|-vvv]\n\n\
+         PATH is the input file, \"-\" reads standard input. Without it the input is read\n\
+         from inputs/YEAR/NAME/day{:02}.txt when a profile is chosen with --profile or\n\
+         ${}, from ${}/day{:02}.txt when the variable is set, otherwise\n\
+         from day{:02}/input.txt. Without --year the year is read from ${}, otherwise\n\
+         it is {}.\n\
+         Every -v shows more diagnostics (info, debug, trace), ${} sets the level as well.",
+        day,
+        day,
+        PROFILE_VARIABLE,
+        INPUT_DIR_VARIABLE,
+        day,
+        day,
+        YEAR_VARIABLE,
+        DEFAULT_YEAR,
+        LOG_VARIABLE
+    )
+}
+
+// Shared main of the day binaries. Resolves and loads the input, hands it to the solvers and
+// turns any failure into an error message with the matching exit code.
+pub fn run_day<F>(day: u8, solve: F)
+where
+    F: FnOnce(&Input) -> Result<(), ParseError>,
+{
+    run(day, |source| {
+        let input = source.load()?;
+        solve(&input).map_err(|error| AocError::from(error).in_file(source.name()))
+    });
+}
+
+// Same as run_day for the days which solve their input a line at a time, so it is never loaded
+// into memory as a whole
+pub fn run_day_streaming<F>(day: u8, solve: F)
+where
+    F: FnOnce(&mut InputLines) -> Result<(), ParseError>,
+{
+    run(day, |source| {
+        let mut lines = source.stream()?;
+        let solved = solve(&mut lines);
+        // A read error ends the lines early, so it goes before any parse error it caused
+        lines.finish()?;
+        solved.map_err(|error| AocError::from(error).in_file(source.name()))
+    });
+}
+
+fn run<F>(day: u8, solve: F)
+where
+    F: FnOnce(&InputSource) -> Result<(), AocError>,
+{
+    let arguments: Vec<String> = env::args().skip(1).collect();
+    if arguments
+        .iter()
+        .any(|argument| argument == "-h" || argument == "--help")
+    {
+        println!("{}", usage(day));
+        return;
+    }
+
+    let result = DayArgs::parse_from(arguments).and_then(|args| {
+        logging::init(args.verbosity);
+        let year = match args.year {
+            Some(year) => year,
+            None => profile::env_year().map_err(AocError::Usage)?,
+        };
+        let profile = match args.profile {
+            Some(profile) => Some(profile),
+            None => profile::env_profile().map_err(AocError::Usage)?,
+        };
+        let source =
+            InputSource::resolve_in_profile(day, args.input.as_deref(), year, profile.as_deref());
+        solve(&source)
+    });
+
+    if let Err(error) = result {
+        eprintln!("Error: {}", error);
+        if let AocError::Usage(_) = error {
+            eprintln!("\n{}", usage(day));
+        }
+        process::exit(error.exit_code());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arguments(arguments: &[&str]) -> Vec<String> {
+        arguments
+            .iter()
+            .map(|argument| String::from(*argument))
+            .collect()
+    }
+
+    #[test]
+    fn check_parse_day_args() {
+        let args = DayArgs::parse_from(arguments(&["--input", "example.txt"])).unwrap();
+        assert_eq!(args.input, Some(PathBuf::from("example.txt")));
+
+        let args = DayArgs::parse_from(arguments(&["--input=-"])).unwrap();
+        assert_eq!(args.input, Some(PathBuf::from("-")));
+
+        let error = DayArgs::parse_from(arguments(&["-i"])).unwrap_err();
+        assert_eq!(error.exit_code(), crate::error::EXIT_USAGE);
+        let args = DayArgs::parse_from(arguments(&["-vv", "--verbose"])).unwrap();
+        assert_eq!(args.verbosity, 3);
+        assert!(DayArgs::parse_from(arguments(&["-vx"])).is_err());
+
+        let args = DayArgs::parse_from(arguments(&["--profile", "alice"])).unwrap();
+        assert_eq!(args.profile.as_deref(), Some("alice"));
+        assert!(DayArgs::parse_from(arguments(&["--profile"])).is_err());
+        assert!(DayArgs::parse_from(arguments(&["--profile", "../x"])).is_err());
+        assert!(DayArgs::parse_from(arguments(&["--profile=a/b"])).is_err());
+
+        let args = DayArgs::parse_from(arguments(&["--year", "2024"])).unwrap();
+        assert_eq!(args.year, Some(2024));
+        let args = DayArgs::parse_from(arguments(&["--year=2025"])).unwrap();
+        assert_eq!(args.year, Some(2025));
+        assert!(DayArgs::parse_from(arguments(&["--year", "24"])).is_err());
+    }
+}
@@ -0,0 +1,193 @@
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::input::InputError;
+
+// Exit codes shared by all the binaries, so scripts can tell why a run failed.
+// Usage errors use the same code as clap, the rest follows sysexits.h.
+pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+pub const EXIT_DATA_ERROR: i32 = 65;
+pub const EXIT_NO_INPUT: i32 = 66;
+
+// Error returned by all the parsers when the input does not have the expected format.
+// Lines and columns start at 1, the same way editors count them. Parsers of a single line
+// do not know where the line comes from, so they report line 1 and the caller moves the
+// error to the right line with `on_line`. The file is only known to the binaries.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, message: &str) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            token: String::from(token),
+            message: String::from(message),
+        }
+    }
+
+    // Creates the error for a token which is a slice of the line, the column is calculated
+    // from the position of the token in the line.
+    pub fn at_token(line: &str, token: &str, message: &str) -> Self {
+        Self::new(1, column_of(line, token), token, message)
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn in_file<P>(mut self, file: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        if self.token.is_empty() {
+            write!(f, ", found end of line")
+        } else {
+            write!(f, ", found `{}`", self.token)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// Everything which can stop a binary before it prints the answers
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AocError {
+    Usage(String),
+    Input(InputError),
+    Parse(ParseError),
+}
+
+impl AocError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AocError::Usage(_) => EXIT_USAGE,
+            AocError::Input(_) => EXIT_NO_INPUT,
+            AocError::Parse(_) => EXIT_DATA_ERROR,
+        }
+    }
+
+    pub fn in_file<P>(self, file: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        match self {
+            AocError::Parse(error) => AocError::Parse(error.in_file(file)),
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Usage(message) => write!(f, "{}", message),
+            AocError::Input(error) => write!(f, "{}", error),
+            AocError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+impl From<InputError> for AocError {
+    fn from(error: InputError) -> Self {
+        AocError::Input(error)
+    }
+}
+
+impl From<ParseError> for AocError {
+    fn from(error: ParseError) -> Self {
+        AocError::Parse(error)
+    }
+}
+
+// Returns the 1 based column of the token inside of the line. If the token is not a slice
+// of the line, the column of its first occurrence is used instead.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let token_start = token.as_ptr() as usize;
+    let offset = if token_start >= line_start && token_start <= line_start + line.len() {
+        token_start - line_start
+    } else {
+        line.find(token).unwrap_or(0)
+    };
+    line[..offset].chars().count() + 1
+}
+
+// Parses a number which is a slice of the line, the error points to the token.
+pub fn parse_number<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::at_token(line, token, "expected a number"))
+}
+
+// Parses every line of the input with a parser of a single line and moves its errors to the
+// line they came from
+pub fn parse_lines<T, F>(input: &str, parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(line).map_err(|error| error.on_line(index + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_error_points_to_token() {
+        let line = "11-22,95-1x5";
+        let token = line.split(['-', ',']).nth(3).unwrap();
+        let error = parse_number::<u64>(line, token).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 10));
+        assert_eq!(
+            error.on_line(4).in_file("input.txt").to_string(),
+            "input.txt:4:10: expected a number, found `1x5`"
+        );
+    }
+
+    #[test]
+    fn check_parse_lines() {
+        assert_eq!(
+            parse_lines("1\n2\n3", |line| parse_number::<u8>(line, line)),
+            Ok(vec![1, 2, 3])
+        );
+        let error = parse_lines("1\nx", |line| parse_number::<u8>(line, line)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn check_column_of_token() {
+        let line = "R48";
+        assert_eq!(column_of(line, &line[1..]), 2);
+        assert_eq!(column_of(line, &line[3..]), 4);
+        assert_eq!(column_of(line, &String::from("8")), 3);
+    }
+}
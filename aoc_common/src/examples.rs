@@ -0,0 +1,200 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const ANNOTATION_START: &str = "<!-- example";
+const ANNOTATION_END: &str = "-->";
+const FENCE: &str = "```";
+
+// The examples of the puzzles live in the README of every day. A fenced code block is an
+// example when there is an annotation with its expected answers right above it:
+//
+// <!-- example part1=21 part2=40 -->
+// ```
+// .......S.......
+// ```
+//
+// The annotation is an HTML comment, so it is not visible on the rendered page. Keys other
+// than part1 and part2 are options of the example, e.g. day 8 connects fewer boxes in its
+// example than in the puzzle: <!-- example part1=40 connections=10 -->
+//
+// An example which a solver deliberately does not solve keeps its answer and lists the parts
+// which are not checked with skip: <!-- example part1=2 skip=1 -->
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    // Line of the annotation in the README, used in the messages of failed examples
+    pub line: usize,
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+    pub options: BTreeMap<String, String>,
+}
+
+impl Example {
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn option<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.options.get(key).and_then(|value| value.parse().ok())
+    }
+
+    pub fn skips(&self, part: u8) -> bool {
+        self.options
+            .get("skip")
+            .is_some_and(|parts| parts.split(',').any(|skipped| skipped == part.to_string()))
+    }
+}
+
+fn parse_annotation(line_number: usize, annotation: &str) -> Result<Example, String> {
+    let mut example = Example {
+        line: line_number,
+        ..Example::default()
+    };
+    for pair in annotation.split_whitespace() {
+        let Some((key, value)) = pair.split_once('=') else {
+            return Err(format!(
+                "README.md:{}: expected key=value in the example annotation, found `{}`",
+                line_number, pair
+            ));
+        };
+        match key {
+            "part1" => example.part_one = Some(String::from(value)),
+            "part2" => example.part_two = Some(String::from(value)),
+            _ => {
+                example
+                    .options
+                    .insert(String::from(key), String::from(value));
+            }
+        }
+    }
+    Ok(example)
+}
+
+// Returns the annotated examples of the README in the order they appear in it
+pub fn parse_examples(readme: &str) -> Result<Vec<Example>, String> {
+    let mut examples = Vec::new();
+    let mut lines = readme.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        let Some(annotation) = line.trim().strip_prefix(ANNOTATION_START) else {
+            continue;
+        };
+        let Some(annotation) = annotation.strip_suffix(ANNOTATION_END) else {
+            return Err(format!(
+                "README.md:{}: expected the example annotation to end on the same line",
+                index + 1
+            ));
+        };
+        let mut example = parse_annotation(index + 1, annotation)?;
+
+        // Only empty lines can be between the annotation and its code block
+        let opening_fence = lines.by_ref().find(|(_, line)| !line.trim().is_empty());
+        if !opening_fence.is_some_and(|(_, line)| line.trim_start().starts_with(FENCE)) {
+            return Err(format!(
+                "README.md:{}: expected a code block after the example annotation",
+                index + 1
+            ));
+        }
+
+        let mut input_lines = Vec::new();
+        let mut closed = false;
+        for (_, line) in lines.by_ref() {
+            if line.trim_start().starts_with(FENCE) {
+                closed = true;
+                break;
+            }
+            input_lines.push(line);
+        }
+        if !closed {
+            return Err(format!(
+                "README.md:{}: expected the code block of the example to be closed",
+                index + 1
+            ));
+        }
+        example.input = input_lines.join("\n");
+        examples.push(example);
+    }
+    Ok(examples)
+}
+
+// Runs every example of the README with an answer for the part and panics on the first
+// wrong answer. A README without any example for the part panics as well, so a typo in the
+// annotation cannot make the test pass without running anything. Only an example which skips
+// the part on purpose is not run.
+pub fn check_examples<F, T>(readme: &str, part: u8, solve: F)
+where
+    F: Fn(&Example) -> T,
+    T: Display,
+{
+    let examples = parse_examples(readme).unwrap_or_else(|error| panic!("{}", error));
+    let mut checked = 0;
+    for example in examples.iter() {
+        let Some(expected) = example.answer(part) else {
+            continue;
+        };
+        checked += 1;
+        if example.skips(part) {
+            continue;
+        }
+        let actual = solve(example).to_string();
+        assert_eq!(
+            actual, expected,
+            "README.md:{}: wrong answer for part {} of the example",
+            example.line, part
+        );
+    }
+    assert!(checked > 0, "README.md has no example for part {}", part);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const README: &str = "\
+# Day 0
+
+<!-- example part1=3 connections=10 -->
+
+```
+1
+2
+```
+
+Part 2 uses another example:
+<!-- example part2=7 -->
+```
+  3 4
+```
+
+<!-- example part1=0 part2=0 skip=1,2 -->
+```
+  3 4
+```
+";
+
+    #[test]
+    fn check_parse_examples() {
+        let examples = parse_examples(README).unwrap();
+        assert_eq!(examples.len(), 3);
+        assert_eq!((examples[0].line, examples[0].input.as_str()), (3, "1\n2"));
+        assert_eq!(
+            (examples[0].answer(1), examples[0].answer(2)),
+            (Some("3"), None)
+        );
+        assert_eq!(examples[0].option::<usize>("connections"), Some(10));
+        assert_eq!(examples[1].input, "  3 4");
+        assert_eq!(examples[1].answer(2), Some("7"));
+        assert!(!examples[1].skips(2));
+        assert!(examples[2].skips(1) && examples[2].skips(2));
+
+        check_examples(README, 1, |example| example.input.lines().count() + 1);
+        check_examples(README, 2, |example| example.input.len() + 2);
+        assert!(parse_examples("<!-- example part1 -->\n```\n1\n```").is_err());
+        assert!(parse_examples("<!-- example part1=1 -->\ntext\n```\n1\n```").is_err());
+        assert!(parse_examples("<!-- example part1=1 -->\n```\n1\n").is_err());
+    }
+}
@@ -0,0 +1,333 @@
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+
+// Rectangular grid of cells for the days whose input is a character map. The cells are stored
+// row by row in a single vector, positions outside of the grid are never indexed with
+// arithmetic by the days: get returns None for them and the neighbours stop at the edges.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub fn new(row: usize, column: usize) -> Self {
+        Position { row, column }
+    }
+
+    // Moves by the offsets, None when the position would leave the grid on the top or the left
+    pub fn offset(self, rows: isize, columns: isize) -> Option<Position> {
+        Some(Position {
+            row: self.row.checked_add_signed(rows)?,
+            column: self.column.checked_add_signed(columns)?,
+        })
+    }
+}
+
+// Which cells around a position are its neighbours
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Neighbourhood {
+    // Up, left, right and down
+    Orthogonal,
+    // The orthogonal neighbours and the diagonal ones
+    Adjacent,
+    // Every cell at most this many rows and columns away
+    Radius(usize),
+}
+
+const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+const ADJACENT_OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+impl Neighbourhood {
+    // Offsets of the neighbours in reading order, without the position itself. The orthogonal
+    // and adjacent ones are constants, the grid days look at them for every cell.
+    fn offsets(self) -> impl Iterator<Item = (isize, isize)> {
+        let (fixed, radius): (&'static [(isize, isize)], isize) = match self {
+            Neighbourhood::Orthogonal => (&ORTHOGONAL_OFFSETS, 0),
+            Neighbourhood::Adjacent => (&ADJACENT_OFFSETS, 0),
+            Neighbourhood::Radius(radius) => (&[], radius as isize),
+        };
+        let square = (-radius..=radius)
+            .flat_map(move |rows| (-radius..=radius).map(move |columns| (rows, columns)))
+            .filter(|offset| *offset != (0, 0));
+        fixed.iter().copied().chain(square)
+    }
+}
+
+// Characters of the cells, used both to parse a grid and to render it
+pub type Symbols<'a, T> = &'a [(char, T)];
+
+// Lists the characters like "., ^ or S" for the error messages
+fn describe(symbols: Symbols<'_, impl Sized>) -> String {
+    let characters: Vec<String> = symbols.iter().map(|(char, _)| char.to_string()).collect();
+    match characters.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::from("nothing"),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    // Parses a character map, one row per line. Every line has to be as wide as the first one
+    // and contain only the characters of the symbols. An empty input is an empty grid.
+    pub fn parse(input: &str, symbols: Symbols<'_, T>) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (index, line) in input.lines().enumerate() {
+            for (column, char) in line.char_indices() {
+                let Some((_, cell)) = symbols.iter().find(|(symbol, _)| *symbol == char) else {
+                    let token = &line[column..column + char.len_utf8()];
+                    let message = format!("expected {}", describe(symbols));
+                    return Err(ParseError::at_token(line, token, &message).on_line(index + 1));
+                };
+                cells.push(cell.clone());
+            }
+            let line_width = line.chars().count();
+            let width = *width.get_or_insert(line_width);
+            if line_width != width {
+                return Err(ParseError::new(
+                    index + 1,
+                    width.min(line_width) + 1,
+                    "",
+                    "expected the line to be as wide as the first line",
+                ));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.row < self.height && position.column < self.width
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.row * self.width + position.column)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    // Every position of the grid in reading order
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| Position { row, column }))
+    }
+
+    // Every cell with its position in reading order
+    pub fn cells(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // Neighbours of the position which are inside of the grid, in reading order
+    pub fn neighbours(
+        &self,
+        position: Position,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = Position> + '_ {
+        neighbourhood
+            .offsets()
+            .filter_map(move |(rows, columns)| position.offset(rows, columns))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    // Cells of the neighbours, for counting the neighbours of some kind
+    pub fn neighbour_cells(
+        &self,
+        position: Position,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = &T> + '_ {
+        self.neighbours(position, neighbourhood)
+            .map(|neighbour| &self[neighbour])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            column < self.width,
+            "column {} is outside of the grid",
+            column
+        );
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn map<U>(&self, mut map: impl FnMut(Position, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self
+                .cells()
+                .map(|(position, cell)| map(position, cell))
+                .collect(),
+        }
+    }
+
+    // Turns the grid back into text, one line per row
+    pub fn render_with(&self, mut symbol: impl FnMut(&T) -> char) -> String {
+        let rows: Vec<String> = self
+            .rows()
+            .map(|row| row.iter().map(&mut symbol).collect())
+            .collect();
+        rows.join("\n")
+    }
+
+    // Renders the grid with the characters it was parsed from, cells without a symbol are ?
+    pub fn render(&self, symbols: Symbols<'_, T>) -> String
+    where
+        T: PartialEq,
+    {
+        self.render_with(|cell| {
+            symbols
+                .iter()
+                .find(|(_, symbol_cell)| symbol_cell == cell)
+                .map_or('?', |(symbol, _)| *symbol)
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SYMBOLS: Symbols<'static, bool> = &[('@', true), ('.', false)];
+
+    #[test]
+    fn check_parse_and_render() {
+        let grid = Grid::parse("..@\n@@.", SYMBOLS).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), [true, true, false]);
+        assert_eq!(
+            grid.column(2).copied().collect::<Vec<bool>>(),
+            [true, false]
+        );
+        assert_eq!(grid.render(SYMBOLS), "..@\n@@.");
+        assert_eq!(
+            grid.render_with(|cell| if *cell { '#' } else { ' ' }),
+            "  #\n## "
+        );
+        assert_eq!(Grid::parse("", SYMBOLS).unwrap().height(), 0);
+
+        let error = Grid::parse("..@\n.#.", SYMBOLS).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 2, "#")
+        );
+        assert_eq!(error.message, "expected @ or .");
+        let error = Grid::parse("..@\n.@", SYMBOLS).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn check_access() {
+        let mut grid = Grid::new(3, 2, 0);
+        grid[Position::new(1, 2)] = 7;
+        assert_eq!(grid.get(Position::new(1, 2)), Some(&7));
+        assert_eq!(grid.get(Position::new(2, 0)), None);
+        assert_eq!(grid.get(Position::new(0, 3)), None);
+        assert_eq!(Position::new(0, 1).offset(-1, 0), None);
+        let doubled = grid.map(|position, cell| position.column + cell * 2);
+        assert_eq!(doubled.row(1), [0, 1, 16]);
+        assert_eq!(grid.cells().filter(|(_, cell)| **cell == 7).count(), 1);
+    }
+
+    #[test]
+    fn check_neighbours() {
+        let grid = Grid::new(4, 4, ());
+        let count = |position, neighbourhood| grid.neighbours(position, neighbourhood).count();
+        assert_eq!(count(Position::new(0, 0), Neighbourhood::Orthogonal), 2);
+        assert_eq!(count(Position::new(1, 1), Neighbourhood::Orthogonal), 4);
+        assert_eq!(count(Position::new(0, 0), Neighbourhood::Adjacent), 3);
+        assert_eq!(count(Position::new(1, 1), Neighbourhood::Adjacent), 8);
+        assert_eq!(count(Position::new(3, 3), Neighbourhood::Radius(2)), 8);
+        assert_eq!(count(Position::new(2, 2), Neighbourhood::Radius(2)), 15);
+        assert!(
+            Neighbourhood::Radius(1)
+                .offsets()
+                .eq(Neighbourhood::Adjacent.offsets())
+        );
+        assert_eq!(
+            grid.neighbours(Position::new(0, 1), Neighbourhood::Orthogonal)
+                .collect::<Vec<Position>>(),
+            [
+                Position::new(0, 0),
+                Position::new(0, 2),
+                Position::new(1, 1)
+            ]
+        );
+    }
+}
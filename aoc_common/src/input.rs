@@ -0,0 +1,392 @@
+use std::collections::VecDeque;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::profile;
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+// Directory with the inputs of all the days named dayNN.txt, used instead of dayNN/input.txt
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+
+// Puzzle input with normalized line endings. No matter where the input comes from, the text
+// never contains a byte order mark, carriage returns or trailing newlines, so every day
+// sees exactly the same lines. Whitespace inside of the lines is kept, day 6 depends on it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    pub fn from_string(text: &str) -> Self {
+        Self {
+            text: normalize(text),
+        }
+    }
+
+    pub fn from_file<P>(path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let text = fs::read_to_string(path)?;
+        Ok(Self::from_string(&text))
+    }
+
+    pub fn from_reader<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        Ok(Self::from_string(&text))
+    }
+
+    pub fn from_stdin() -> io::Result<Self> {
+        Self::from_reader(io::stdin().lock())
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.text.lines().map(String::from).collect()
+    }
+}
+
+fn normalize(text: &str) -> String {
+    let text = text.strip_prefix(BYTE_ORDER_MARK).unwrap_or(text);
+    let lines: Vec<&str> = text.split_inclusive('\n').flat_map(split_lines).collect();
+    String::from(lines.join("\n").trim_end_matches('\n'))
+}
+
+// Lines of a piece of the input which ends with a line feed or at the end of the input, shared by
+// Input and InputLines. A carriage return ends a line as well, on its own (old Mac) or right
+// before the line feed (Windows).
+fn split_lines(piece: &str) -> impl Iterator<Item = &str> {
+    let piece = piece.strip_suffix('\n').unwrap_or(piece);
+    let piece = piece.strip_suffix('\r').unwrap_or(piece);
+    piece.split('\r')
+}
+
+// The input could not be loaded at all, as opposed to ParseError where it has a wrong format
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputError {
+    pub source: InputSource,
+    pub kind: io::ErrorKind,
+    pub message: String,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Cannot read input {}: {}", self.source, self.message)?;
+        if self.kind == io::ErrorKind::NotFound {
+            write!(
+                f,
+                " (pass the file with --input or set {} to the directory with the inputs)",
+                INPUT_DIR_VARIABLE
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for InputError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    // Picks the input of the day. An explicit path always wins ("-" means standard input),
+    // then the input of the profile in AOC_PROFILE (of the year in AOC_YEAR), the AOC_INPUT_DIR
+    // directory and lastly the input.txt next to the day's sources. Fails when one of the
+    // variables is not a valid year or profile.
+    pub fn resolve(day: u8, input: Option<&Path>) -> Result<Self, String> {
+        let year = profile::env_year()?;
+        let profile = profile::env_profile()?;
+        Ok(Self::resolve_in_profile(
+            day,
+            input,
+            year,
+            profile.as_deref(),
+        ))
+    }
+
+    // Same as resolve, with the year and the profile chosen by the caller, who checked them
+    pub fn resolve_in_profile(
+        day: u8,
+        input: Option<&Path>,
+        year: u16,
+        profile: Option<&str>,
+    ) -> Self {
+        Self::resolve_with(
+            day,
+            input,
+            year,
+            profile,
+            env::var_os(INPUT_DIR_VARIABLE).map(PathBuf::from),
+        )
+    }
+
+    fn resolve_with(
+        day: u8,
+        input: Option<&Path>,
+        year: u16,
+        profile: Option<&str>,
+        input_dir: Option<PathBuf>,
+    ) -> Self {
+        match (input, profile, input_dir) {
+            (Some(path), _, _) if path == Path::new("-") => InputSource::Stdin,
+            (Some(path), _, _) => InputSource::File(path.to_path_buf()),
+            (None, Some(profile), _) => InputSource::File(profile::input_path(year, profile, day)),
+            (None, None, Some(input_dir)) => InputSource::File(input_dir.join(day_file_name(day))),
+            (None, None, None) => InputSource::File(default_input_path(day)),
+        }
+    }
+
+    // Name used in error messages
+    pub fn name(&self) -> PathBuf {
+        match self {
+            InputSource::Stdin => PathBuf::from("<stdin>"),
+            InputSource::File(path) => path.clone(),
+        }
+    }
+
+    fn error(&self, error: io::Error) -> InputError {
+        InputError {
+            source: self.clone(),
+            kind: error.kind(),
+            message: error.to_string(),
+        }
+    }
+
+    pub fn load(&self) -> Result<Input, InputError> {
+        let input = match self {
+            InputSource::Stdin => Input::from_stdin(),
+            InputSource::File(path) => Input::from_file(path),
+        };
+        input.map_err(|error| self.error(error))
+    }
+
+    // Reads the input a line at a time instead of loading all of it
+    pub fn stream(&self) -> Result<InputLines, InputError> {
+        let reader: Box<dyn BufRead> = match self {
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+            InputSource::File(path) => Box::new(BufReader::new(
+                fs::File::open(path).map_err(|error| self.error(error))?,
+            )),
+        };
+        Ok(InputLines::new(self.clone(), reader))
+    }
+}
+
+// Lines of an input which is too large to keep in memory, normalized like Input: without the byte
+// order mark, the carriage returns and the empty lines at the end. The lines stop at the first
+// read error, which `finish` returns once the lines are consumed.
+pub struct InputLines {
+    source: InputSource,
+    reader: Box<dyn BufRead>,
+    first_line: bool,
+    // Lines of the last read piece which are not returned yet, more than one when it contains
+    // carriage returns
+    read_lines: VecDeque<String>,
+    // Empty lines are held back until a line with content follows, trailing ones are dropped
+    held_back_empty_lines: usize,
+    pending_line: Option<String>,
+    error: Option<InputError>,
+}
+
+impl InputLines {
+    pub fn new(source: InputSource, reader: Box<dyn BufRead>) -> Self {
+        InputLines {
+            source,
+            reader,
+            first_line: true,
+            read_lines: VecDeque::new(),
+            held_back_empty_lines: 0,
+            pending_line: None,
+            error: None,
+        }
+    }
+
+    pub fn finish(self) -> Result<(), InputError> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    fn read_line(&mut self) -> Option<String> {
+        while self.read_lines.is_empty() {
+            let mut piece = String::new();
+            match self.reader.read_line(&mut piece) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(error) => {
+                    self.error = Some(self.source.error(error));
+                    return None;
+                }
+            }
+            let mut piece = piece.as_str();
+            if self.first_line {
+                self.first_line = false;
+                piece = piece.strip_prefix(BYTE_ORDER_MARK).unwrap_or(piece);
+            }
+            self.read_lines.extend(split_lines(piece).map(String::from));
+        }
+        self.read_lines.pop_front()
+    }
+}
+
+impl Iterator for InputLines {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.pending_line.is_some() {
+            if self.held_back_empty_lines > 0 {
+                self.held_back_empty_lines -= 1;
+                return Some(String::new());
+            }
+            return self.pending_line.take();
+        }
+        loop {
+            // At the end of the input the held back empty lines are trailing ones
+            let line = self.read_line()?;
+            if !line.is_empty() {
+                self.pending_line = Some(line);
+                return self.next();
+            }
+            self.held_back_empty_lines += 1;
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name().display())
+    }
+}
+
+pub fn day_file_name(day: u8) -> String {
+    format!("day{:02}.txt", day)
+}
+
+// Root of the workspace, so the inputs are found no matter which directory the binaries run from
+pub fn workspace_root() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir).to_path_buf()
+}
+
+pub fn default_input_path(day: u8) -> PathBuf {
+    workspace_root()
+        .join(format!("day{:02}", day))
+        .join("input.txt")
+}
+
+// Shorthand for the days which only need the lines of the input file
+pub fn read_lines<P>(path: P) -> Result<Vec<String>, InputError>
+where
+    P: AsRef<Path>,
+{
+    Ok(InputSource::File(path.as_ref().to_path_buf())
+        .load()?
+        .lines())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_line_endings_are_normalized() {
+        let input = Input::from_string("\u{feff}L68\r\nL30\rR48\n\n");
+        assert_eq!(input.text(), "L68\nL30\nR48");
+        assert_eq!(input.lines(), vec!["L68", "L30", "R48"]);
+    }
+
+    #[test]
+    fn check_inner_whitespace_is_kept() {
+        let input = Input::from_reader("123 328  51 64 \n\n  6 98  215 314\n".as_bytes()).unwrap();
+        assert_eq!(
+            input.lines(),
+            vec!["123 328  51 64 ", "", "  6 98  215 314"]
+        );
+    }
+
+    #[test]
+    fn check_input_resolution() {
+        let explicit = Path::new("my-input.txt");
+        let input_dir = Some(PathBuf::from("/inputs"));
+        assert_eq!(
+            InputSource::resolve_with(7, Some(explicit), 2025, Some("alice"), input_dir.clone()),
+            InputSource::File(PathBuf::from("my-input.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve_with(7, Some(Path::new("-")), 2025, None, None),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::resolve_with(7, None, 2025, Some("alice"), input_dir.clone()),
+            InputSource::File(workspace_root().join("inputs/2025/alice/day07.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve_with(7, None, 2024, Some("alice"), input_dir.clone()),
+            InputSource::File(workspace_root().join("inputs/2024/alice/day07.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve_with(7, None, 2025, None, input_dir),
+            InputSource::File(PathBuf::from("/inputs/day07.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve_with(7, None, 2025, None, None),
+            InputSource::File(workspace_root().join("day07").join("input.txt"))
+        );
+    }
+
+    #[test]
+    fn check_missing_input() {
+        let error = read_lines("does-not-exist.txt").unwrap_err();
+        assert_eq!(error.kind, io::ErrorKind::NotFound);
+        assert!(error.to_string().contains("--input"));
+
+        let source = InputSource::File(PathBuf::from("does-not-exist.txt"));
+        assert_eq!(source.stream().err().unwrap().kind, io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn check_streamed_lines_are_normalized() {
+        let text = "\u{feff}3-5\r\n10-14\n\n\n17\r\n\n\n";
+        let reader = Box::new(io::Cursor::new(text));
+        let mut lines = InputLines::new(InputSource::Stdin, reader);
+        let streamed: Vec<String> = lines.by_ref().collect();
+        assert_eq!(streamed, Input::from_string(text).lines());
+        assert_eq!(streamed, ["3-5", "10-14", "", "", "17"]);
+        assert!(lines.finish().is_ok());
+    }
+
+    #[test]
+    fn check_both_modes_agree_on_line_endings() {
+        let stream = |text: &str| -> Vec<String> {
+            let reader = Box::new(io::Cursor::new(String::from(text)));
+            InputLines::new(InputSource::Stdin, reader).collect()
+        };
+        let texts = [
+            ("L68\r\nL30\r\n\r\nR48\r\n", vec!["L68", "L30", "", "R48"]),
+            ("L68\rL30\r\rR48\r", vec!["L68", "L30", "", "R48"]),
+            (
+                "\u{feff}L68\rL30\r\nR48\n\rL5\r",
+                vec!["L68", "L30", "R48", "", "L5"],
+            ),
+            ("L68\r\n\r\n\r", vec!["L68"]),
+            ("\r\nL68", vec!["", "L68"]),
+        ];
+        for (text, expected) in texts {
+            assert_eq!(Input::from_string(text).lines(), expected, "{:?}", text);
+            assert_eq!(stream(text), expected, "{:?}", text);
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pub mod cancel;
+pub mod checked;
+pub mod cli;
+pub mod error;
+pub mod examples;
+pub mod grid;
+pub mod input;
+pub mod logging;
+pub mod profile;
+pub mod recorder;
+pub mod wide;
+
+pub use cli::{DayArgs, run_day, run_day_streaming};
+pub use error::{AocError, ParseError};
+pub use input::{Input, InputError, InputLines, InputSource, read_lines};
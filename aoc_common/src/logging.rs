@@ -0,0 +1,68 @@
+use std::env;
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+// Level used when there is no -v flag, e.g. AOC_LOG=debug
+pub const LOG_VARIABLE: &str = "AOC_LOG";
+
+// Writes the messages to standard error, so they never mix with the answers on standard output
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{}] {}", record.level(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+// Quiet by default, only errors are shown. Every -v shows one more level: info, debug and trace.
+pub fn level_from_verbosity(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::Error,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+// The -v flag wins over the variable, an unknown level in the variable is ignored
+fn resolve_level(verbosity: u8, variable: Option<&str>) -> LevelFilter {
+    if verbosity > 0 {
+        return level_from_verbosity(verbosity);
+    }
+    variable
+        .and_then(|level| level.trim().parse().ok())
+        .unwrap_or(LevelFilter::Error)
+}
+
+pub fn init(verbosity: u8) {
+    let variable = env::var(LOG_VARIABLE).ok();
+    // Only fails when a logger is already installed, which keeps its level
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(resolve_level(verbosity, variable.as_deref()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_log_level() {
+        assert_eq!(resolve_level(0, None), LevelFilter::Error);
+        assert_eq!(resolve_level(2, None), LevelFilter::Debug);
+        assert_eq!(resolve_level(5, None), LevelFilter::Trace);
+        assert_eq!(resolve_level(0, Some("trace")), LevelFilter::Trace);
+        assert_eq!(resolve_level(1, Some("trace")), LevelFilter::Info);
+        assert_eq!(resolve_level(0, Some("loud")), LevelFilter::Error);
+    }
+}
@@ -0,0 +1,173 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::input::{day_file_name, default_input_path, workspace_root};
+
+// Profiles are the inputs of different accounts. Every profile has its own folder
+// inputs/<year>/<profile> with the inputs named dayNN.txt and the accepted answers in
+// answers.toml. The default profile is the layout the repository started with: the input.txt
+// of every day and answers.toml in the root, it does not depend on the year.
+
+// Year used when none is passed with --year
+pub const YEAR_VARIABLE: &str = "AOC_YEAR";
+
+pub const DEFAULT_YEAR: u16 = 2025;
+
+// The first Advent of Code
+const FIRST_YEAR: u16 = 2015;
+
+// Profile used when none is passed with --profile
+pub const PROFILE_VARIABLE: &str = "AOC_PROFILE";
+
+pub const DEFAULT_PROFILE: &str = "default";
+
+// Runs every profile, cannot be the name of a profile
+pub const ALL_PROFILES: &str = "all";
+
+pub fn parse_year(year: &str) -> Result<u16, String> {
+    Some(year)
+        .filter(|year| year.bytes().all(|byte| byte.is_ascii_digit()))
+        .and_then(|year| year.parse().ok())
+        .filter(|year| (FIRST_YEAR..=9999).contains(year))
+        .ok_or_else(|| {
+            format!(
+                "Year {} is not a year of Advent of Code, expected {} or later",
+                year, FIRST_YEAR
+            )
+        })
+}
+
+// A profile is a folder in inputs/<year>, so its name cannot lead out of it
+pub fn check_profile(profile: &str) -> Result<(), String> {
+    let valid = profile
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    match valid && !profile.is_empty() {
+        true => Ok(()),
+        false => Err(format!(
+            "Profile \"{}\" is not a valid name, it can only contain letters, digits, - and _",
+            profile
+        )),
+    }
+}
+
+pub fn inputs_dir(year: u16) -> PathBuf {
+    workspace_root().join("inputs").join(year.to_string())
+}
+
+pub fn profile_dir(year: u16, profile: &str) -> PathBuf {
+    match profile {
+        DEFAULT_PROFILE => workspace_root(),
+        profile => inputs_dir(year).join(profile),
+    }
+}
+
+pub fn input_path(year: u16, profile: &str, day: u8) -> PathBuf {
+    match profile {
+        DEFAULT_PROFILE => default_input_path(day),
+        profile => profile_dir(year, profile).join(day_file_name(day)),
+    }
+}
+
+pub fn answers_path(year: u16, profile: &str) -> PathBuf {
+    profile_dir(year, profile).join("answers.toml")
+}
+
+// The year in AOC_YEAR, otherwise the default one
+pub fn env_year() -> Result<u16, String> {
+    match env::var(YEAR_VARIABLE) {
+        Ok(year) if !year.is_empty() => {
+            parse_year(&year).map_err(|error| format!("{} in ${}", error, YEAR_VARIABLE))
+        }
+        _ => Ok(DEFAULT_YEAR),
+    }
+}
+
+pub fn env_profile() -> Result<Option<String>, String> {
+    match env::var(PROFILE_VARIABLE) {
+        Ok(profile) if !profile.is_empty() => match check_profile(&profile) {
+            Ok(()) => Ok(Some(profile)),
+            Err(error) => Err(format!("{} in ${}", error, PROFILE_VARIABLE)),
+        },
+        _ => Ok(None),
+    }
+}
+
+// The default profile and every folder in inputs/<year>, sorted by name
+pub fn profiles(year: u16) -> Vec<String> {
+    list_profiles(&inputs_dir(year))
+}
+
+fn list_profiles(inputs_dir: &Path) -> Vec<String> {
+    let mut profiles: Vec<String> = fs::read_dir(inputs_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name != DEFAULT_PROFILE && name != ALL_PROFILES)
+        .filter(|name| check_profile(name).is_ok())
+        .collect();
+    profiles.sort();
+    profiles.insert(0, String::from(DEFAULT_PROFILE));
+    profiles
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn check_profile_paths() {
+        assert_eq!(input_path(2024, DEFAULT_PROFILE, 7), default_input_path(7));
+        assert_eq!(
+            input_path(2025, "alice", 7),
+            workspace_root().join("inputs/2025/alice/day07.txt")
+        );
+        assert_eq!(
+            input_path(2024, "alice", 7),
+            workspace_root().join("inputs/2024/alice/day07.txt")
+        );
+        assert_eq!(
+            answers_path(2025, DEFAULT_PROFILE),
+            workspace_root().join("answers.toml")
+        );
+        assert_eq!(
+            answers_path(2025, "alice"),
+            workspace_root().join("inputs/2025/alice/answers.toml")
+        );
+    }
+
+    #[test]
+    fn check_years_and_names() {
+        assert_eq!(parse_year("2024"), Ok(2024));
+        assert!(parse_year("2014").is_err());
+        assert!(parse_year("25").is_err());
+        assert!(parse_year("+2025").is_err());
+
+        assert!(check_profile("alice").is_ok());
+        assert!(check_profile("work_2-b").is_ok());
+        for profile in ["", "..", "../x", "a/b", "a\\b", "/tmp", ".hidden", "a b"] {
+            assert!(check_profile(profile).is_err(), "{}", profile);
+        }
+    }
+
+    #[test]
+    fn check_list_profiles() {
+        let inputs_dir = env::temp_dir().join(format!("aoc-profiles-test-{}", process::id()));
+        assert_eq!(list_profiles(&inputs_dir), [DEFAULT_PROFILE]);
+
+        for profile in ["carol", "alice", "all", ".git"] {
+            fs::create_dir_all(inputs_dir.join(profile)).unwrap();
+        }
+        fs::write(inputs_dir.join("notes.txt"), "").unwrap();
+        assert_eq!(
+            list_profiles(&inputs_dir),
+            [DEFAULT_PROFILE, "alice", "carol"]
+        );
+        fs::remove_dir_all(&inputs_dir).unwrap();
+    }
+}
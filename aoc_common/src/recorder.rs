@@ -0,0 +1,243 @@
+use std::cell::RefCell;
+use std::fmt::{self, Display};
+
+use crate::error::ParseError;
+
+// Step by step traces of the simulating solvers. A solver describes what it does with events,
+// like the dial of day 1 after every rotation, which are only built and kept while the part runs
+// inside of `recording`, so the solvers do not pay for them otherwise.
+//
+// A trace is written as text, one line per event:
+//
+//   aoc-trace 1 day=1 part=2 answer=6
+//   rotate rotation=-68 position=82 zeros=0 clicks=1
+//   rotate rotation=-30 position=52 zeros=0 clicks=1
+//
+// The first line names the format and its version, followed by the fields of the whole trace.
+// Every other line is an event: its name and its fields as key=value pairs, separated by single
+// spaces. A value never contains a space, `=`, `%` or a line break, those are written as %20,
+// %3D, %25 and %0A. Empty lines and lines starting with # are ignored.
+
+const FORMAT: &str = "aoc-trace";
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    pub name: String,
+    pub fields: Vec<(String, String)>,
+}
+
+impl Event {
+    pub fn new(name: &str) -> Self {
+        Event {
+            name: name.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, key: &str, value: impl Display) -> Self {
+        self.fields.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace(' ', "%20")
+        .replace('=', "%3D")
+        .replace('\n', "%0A")
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("%20", " ")
+        .replace("%3D", "=")
+        .replace("%0A", "\n")
+        .replace("%25", "%")
+}
+
+fn write_fields(f: &mut fmt::Formatter<'_>, fields: &[(String, String)]) -> fmt::Result {
+    for (key, value) in fields.iter() {
+        write!(f, " {}={}", escape(key), escape(value))?;
+    }
+    Ok(())
+}
+
+// The line of the event in a trace
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", escape(&self.name))?;
+        write_fields(f, &self.fields)
+    }
+}
+
+// Reads the key=value pairs of a line, `tokens` are the words after the name
+fn parse_fields<'a>(
+    line: &str,
+    tokens: impl Iterator<Item = &'a str>,
+) -> Result<Vec<(String, String)>, ParseError> {
+    tokens
+        .map(|token| match token.split_once('=') {
+            Some((key, value)) if !key.is_empty() => Ok((unescape(key), unescape(value))),
+            _ => Err(ParseError::at_token(
+                line,
+                token,
+                "expected a field like key=value",
+            )),
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Trace {
+    // Fields of the whole trace, like the day, the part and the answer
+    pub fields: Vec<(String, String)>,
+    pub events: Vec<Event>,
+}
+
+impl Trace {
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+        let Some((index, header)) = lines.next() else {
+            return Err(ParseError::new(1, 1, "", "expected a trace"));
+        };
+        let mut tokens = header.split(' ');
+        let (Some(FORMAT), Some(version)) = (tokens.next(), tokens.next()) else {
+            return Err(
+                ParseError::at_token(header, header, "expected a line like aoc-trace 1")
+                    .on_line(index + 1),
+            );
+        };
+        if version != FORMAT_VERSION.to_string() {
+            return Err(ParseError::at_token(
+                header,
+                version,
+                &format!("expected version {} of the format", FORMAT_VERSION),
+            )
+            .on_line(index + 1));
+        }
+        let mut trace = Trace {
+            fields: parse_fields(header, tokens).map_err(|error| error.on_line(index + 1))?,
+            events: Vec::new(),
+        };
+
+        for (index, line) in lines {
+            let mut tokens = line.split(' ');
+            let name = tokens.next().unwrap_or_default();
+            let fields = parse_fields(line, tokens).map_err(|error| error.on_line(index + 1))?;
+            trace.events.push(Event {
+                name: unescape(name),
+                fields,
+            });
+        }
+        Ok(trace)
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", FORMAT, FORMAT_VERSION)?;
+        write_fields(f, &self.fields)?;
+        writeln!(f)?;
+        for event in self.events.iter() {
+            writeln!(f, "{}", event)?;
+        }
+        Ok(())
+    }
+}
+
+// The events recorded so far, None when the thread does not record
+thread_local! {
+    static EVENTS: RefCell<Option<Vec<Event>>> = const { RefCell::new(None) };
+}
+
+// Runs the function and returns the events it recorded on the current thread
+pub fn recording<R>(run: impl FnOnce() -> R) -> (R, Vec<Event>) {
+    let previous = EVENTS.replace(Some(Vec::new()));
+    let result = run();
+    let events = EVENTS.replace(previous).unwrap_or_default();
+    (result, events)
+}
+
+pub fn is_recording() -> bool {
+    EVENTS.with_borrow(Option::is_some)
+}
+
+// Records the event, which is only built when the thread records
+pub fn record(event: impl FnOnce() -> Event) {
+    EVENTS.with_borrow_mut(|events| {
+        if let Some(events) = events {
+            events.push(event());
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_recording() {
+        record(|| panic!("the event is not built without recording"));
+        let (answer, events) = recording(|| {
+            record(|| {
+                Event::new("rotate")
+                    .with("rotation", -68)
+                    .with("position", 82)
+            });
+            record(|| Event::new("stop"));
+            3
+        });
+        assert_eq!(answer, 3);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].to_string(), "rotate rotation=-68 position=82");
+        assert_eq!(events[0].field("position"), Some("82"));
+        assert!(!is_recording());
+    }
+
+    #[test]
+    fn check_trace_format() {
+        let trace = Trace {
+            fields: vec![(String::from("day"), String::from("1"))],
+            events: vec![
+                Event::new("rotate").with("position", 82),
+                Event::new("note").with("text", "a = 50% of\nit"),
+            ],
+        };
+        let text = trace.to_string();
+        assert_eq!(
+            text,
+            "aoc-trace 1 day=1\nrotate position=82\nnote text=a%20%3D%2050%25%20of%0Ait\n"
+        );
+        assert_eq!(Trace::parse(&text).unwrap(), trace);
+        assert_eq!(
+            Trace::parse(&format!("# comment\n\n{}", text)).unwrap(),
+            trace
+        );
+
+        let error = Trace::parse("aoc-trace 1\nrotate position").unwrap_err();
+        assert_eq!((error.line, error.token.as_str()), (2, "position"));
+        let error = Trace::parse("aoc-trace 2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 11));
+        assert!(Trace::parse("").is_err());
+    }
+}
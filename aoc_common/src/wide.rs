@@ -0,0 +1,251 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Mul, MulAssign};
+use std::str::FromStr;
+
+// Unsigned integer without an upper limit, for the answers which outgrow u64 on large inputs:
+// the products of day 6 and day 8 and the path counts of day 11. The solvers only add and
+// multiply, so that is all it does. The digits are stored in base 10^9, least significant
+// first, which keeps printing the answers cheap.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Wide {
+    // Never ends with a zero limb, zero has no limbs at all
+    limbs: Vec<u32>,
+}
+
+const BASE: u64 = 1_000_000_000;
+const BASE_DIGITS: usize = 9;
+
+impl Wide {
+    pub fn zero() -> Self {
+        Wide::default()
+    }
+
+    pub fn one() -> Self {
+        Wide::from(1u64)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        self.limbs.iter().rev().try_fold(0u64, |value, limb| {
+            value.checked_mul(BASE)?.checked_add(u64::from(*limb))
+        })
+    }
+
+    fn trim(mut self) -> Self {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+}
+
+impl From<u64> for Wide {
+    fn from(mut value: u64) -> Self {
+        let mut limbs = Vec::new();
+        while value > 0 {
+            limbs.push((value % BASE) as u32);
+            value /= BASE;
+        }
+        Wide { limbs }
+    }
+}
+
+impl From<u32> for Wide {
+    fn from(value: u32) -> Self {
+        Wide::from(u64::from(value))
+    }
+}
+
+impl From<usize> for Wide {
+    fn from(value: usize) -> Self {
+        Wide::from(value as u64)
+    }
+}
+
+impl FromStr for Wide {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, String> {
+        if text.is_empty() || !text.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(format!("{} is not an unsigned integer", text));
+        }
+        let mut limbs = Vec::new();
+        let mut end = text.len();
+        while end > 0 {
+            let start = end.saturating_sub(BASE_DIGITS);
+            limbs.push(text[start..end].parse().expect("the limb has only digits"));
+            end = start;
+        }
+        Ok(Wide { limbs }.trim())
+    }
+}
+
+impl fmt::Display for Wide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((most_significant, rest)) = self.limbs.split_last() else {
+            return f.pad("0");
+        };
+        let mut digits = most_significant.to_string();
+        for limb in rest.iter().rev() {
+            digits.push_str(&format!("{:09}", limb));
+        }
+        f.pad(&digits)
+    }
+}
+
+impl Ord for Wide {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for Wide {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl AddAssign<&Wide> for Wide {
+    fn add_assign(&mut self, other: &Wide) {
+        if self.limbs.len() < other.limbs.len() {
+            self.limbs.resize(other.limbs.len(), 0);
+        }
+        let mut carry = 0;
+        for (index, limb) in self.limbs.iter_mut().enumerate() {
+            let sum = u64::from(*limb) + u64::from(other.limbs.get(index).copied().unwrap_or(0));
+            let sum = sum + carry;
+            *limb = (sum % BASE) as u32;
+            carry = sum / BASE;
+            if carry == 0 && index >= other.limbs.len() {
+                break;
+            }
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+}
+
+impl AddAssign for Wide {
+    fn add_assign(&mut self, other: Wide) {
+        *self += &other;
+    }
+}
+
+impl Add for Wide {
+    type Output = Wide;
+
+    fn add(mut self, other: Wide) -> Wide {
+        self += &other;
+        self
+    }
+}
+
+impl Add<&Wide> for &Wide {
+    type Output = Wide;
+
+    fn add(self, other: &Wide) -> Wide {
+        let mut sum = self.clone();
+        sum += other;
+        sum
+    }
+}
+
+impl Mul<&Wide> for &Wide {
+    type Output = Wide;
+
+    fn mul(self, other: &Wide) -> Wide {
+        if self.is_zero() || other.is_zero() {
+            return Wide::zero();
+        }
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, left) in self.limbs.iter().enumerate() {
+            let mut carry = 0;
+            for (j, right) in other.limbs.iter().enumerate() {
+                let product =
+                    u64::from(limbs[i + j]) + u64::from(*left) * u64::from(*right) + carry;
+                limbs[i + j] = (product % BASE) as u32;
+                carry = product / BASE;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+        Wide { limbs }.trim()
+    }
+}
+
+impl Mul for Wide {
+    type Output = Wide;
+
+    fn mul(self, other: Wide) -> Wide {
+        &self * &other
+    }
+}
+
+impl MulAssign<&Wide> for Wide {
+    fn mul_assign(&mut self, other: &Wide) {
+        *self = &*self * other;
+    }
+}
+
+impl Sum for Wide {
+    fn sum<I: Iterator<Item = Wide>>(iter: I) -> Self {
+        iter.fold(Wide::zero(), |sum, value| sum + value)
+    }
+}
+
+impl Product for Wide {
+    fn product<I: Iterator<Item = Wide>>(iter: I) -> Self {
+        iter.fold(Wide::one(), |product, value| product * value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_arithmetic() {
+        let max = Wide::from(u64::MAX);
+        assert_eq!((&max + &Wide::one()).to_string(), "18446744073709551616");
+        assert_eq!(
+            (&max * &max).to_string(),
+            "340282366920938463426481119284349108225"
+        );
+        assert_eq!((&max * &Wide::zero()), Wide::zero());
+        assert_eq!(
+            [999_999_999u64, 1, 1_000_000_000]
+                .into_iter()
+                .map(Wide::from)
+                .sum::<Wide>()
+                .to_string(),
+            "2000000000"
+        );
+        assert_eq!(
+            (1..=25u64).map(Wide::from).product::<Wide>().to_string(),
+            "15511210043330985984000000"
+        );
+    }
+
+    #[test]
+    fn check_conversions() {
+        assert_eq!(Wide::zero().to_string(), "0");
+        assert_eq!(Wide::from(1_000_000_007u64).to_string(), "1000000007");
+        assert_eq!(
+            "000018446744073709551616".parse::<Wide>().unwrap(),
+            &Wide::from(u64::MAX) + &Wide::one()
+        );
+        assert_eq!(Wide::from(u64::MAX).to_u64(), Some(u64::MAX));
+        assert_eq!((&Wide::from(u64::MAX) + &Wide::one()).to_u64(), None);
+        assert!("12a".parse::<Wide>().is_err());
+        assert!(Wide::from(10u64) < Wide::from(1_000_000_000u64));
+        assert!(Wide::from(2_000_000_001u64) > Wide::from(1_000_000_002u64));
+    }
+}
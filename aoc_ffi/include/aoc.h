@@ -0,0 +1,42 @@
+/* Generated by aoc_ffi::header, do not edit. Rerun the tests of aoc_ffi with
+ * AOC_UPDATE_HEADER=1 to update it. */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The answer is in the output buffer */
+#define AOC_OK 0
+/* The day is not solved */
+#define AOC_UNSUPPORTED_DAY 1
+/* The day does not have the part */
+#define AOC_UNSUPPORTED_PART 2
+/* The input has a wrong format, the error message is in the output buffer */
+#define AOC_PARSE_ERROR 3
+/* The input is not UTF-8 */
+#define AOC_INVALID_UTF8 4
+/* The output buffer is too small, out_len holds the size it needs */
+#define AOC_BUFFER_TOO_SMALL 5
+/* A pointer other than an empty input is NULL */
+#define AOC_NULL_POINTER 6
+/* The solver failed or panicked, the error message is in the output buffer */
+#define AOC_SOLVER_FAILED 7
+
+/* Solves the part of the day on the input_len bytes at input. *out_len is the size of
+ * out when called. The answer, or the error message, is written to out with a
+ * terminating NUL and *out_len is set to its length without the NUL. When it does not
+ * fit, nothing is written and *out_len is set to the size of the buffer it needs.
+ * Returns AOC_OK or one of the errors above. */
+int32_t aoc_solve(uint8_t day, uint8_t part, const uint8_t *input, size_t input_len,
+                  uint8_t *out, size_t *out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC_H */
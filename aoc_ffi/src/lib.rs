@@ -0,0 +1,251 @@
+//! C interface of the solvers, for test rigs written in C or C++. The library is built as a
+//! `cdylib` and `include/aoc.h` declares its functions, see `header` for how the header is
+//! kept up to date.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+use aoc::days;
+use aoc::solution::{Part, SolveError};
+use aoc_common::Input;
+
+/// Result codes of `aoc_solve`, everything except `Ok` is an error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(i32)]
+pub enum Status {
+    Ok = 0,
+    UnsupportedDay = 1,
+    UnsupportedPart = 2,
+    ParseError = 3,
+    InvalidUtf8 = 4,
+    BufferTooSmall = 5,
+    NullPointer = 6,
+    SolverFailed = 7,
+}
+
+impl Status {
+    pub const ALL: [Status; 8] = [
+        Status::Ok,
+        Status::UnsupportedDay,
+        Status::UnsupportedPart,
+        Status::ParseError,
+        Status::InvalidUtf8,
+        Status::BufferTooSmall,
+        Status::NullPointer,
+        Status::SolverFailed,
+    ];
+
+    /// Name of the constant in the header.
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Ok => "AOC_OK",
+            Status::UnsupportedDay => "AOC_UNSUPPORTED_DAY",
+            Status::UnsupportedPart => "AOC_UNSUPPORTED_PART",
+            Status::ParseError => "AOC_PARSE_ERROR",
+            Status::InvalidUtf8 => "AOC_INVALID_UTF8",
+            Status::BufferTooSmall => "AOC_BUFFER_TOO_SMALL",
+            Status::NullPointer => "AOC_NULL_POINTER",
+            Status::SolverFailed => "AOC_SOLVER_FAILED",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Status::Ok => "The answer is in the output buffer",
+            Status::UnsupportedDay => "The day is not solved",
+            Status::UnsupportedPart => "The day does not have the part",
+            Status::ParseError => {
+                "The input has a wrong format, the error message is in the output buffer"
+            }
+            Status::InvalidUtf8 => "The input is not UTF-8",
+            Status::BufferTooSmall => {
+                "The output buffer is too small, out_len holds the size it needs"
+            }
+            Status::NullPointer => "A pointer other than an empty input is NULL",
+            Status::SolverFailed => {
+                "The solver failed or panicked, the error message is in the output buffer"
+            }
+        }
+    }
+}
+
+/// Solves the part of the day, the same as `aoc run` would. The answer or the error message
+/// is written as text to the output buffer.
+pub fn solve(day: u8, part: u8, input: &[u8]) -> (Status, String) {
+    let Some(solution) = days::find(day) else {
+        return (Status::UnsupportedDay, format!("Day {} is not solved", day));
+    };
+    let Some(part) = Part::from_number(part).filter(|part| solution.parts().contains(part)) else {
+        return (
+            Status::UnsupportedPart,
+            format!("Day {} does not have part {}", day, part),
+        );
+    };
+    let Ok(text) = str::from_utf8(input) else {
+        return (Status::InvalidUtf8, String::from("The input is not UTF-8"));
+    };
+    let input = Input::from_string(text);
+
+    // A panic must not unwind into the C code
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, input.text())));
+    match answer {
+        Ok(Ok(answer)) => (Status::Ok, answer),
+        Ok(Err(error @ SolveError::Parse(_))) => (Status::ParseError, error.to_string()),
+        Ok(Err(error)) => (Status::SolverFailed, error.to_string()),
+        Err(_) => (Status::SolverFailed, String::from("The solver panicked")),
+    }
+}
+
+/// Solves the part of the day on the `input_len` bytes at `input`.
+///
+/// `*out_len` is the size of `out` when called. The text is written to `out` with a
+/// terminating NUL and `*out_len` is set to its length without the NUL. When it does not fit,
+/// nothing is written and `*out_len` is set to the size of the buffer it needs.
+///
+/// # Safety
+///
+/// `input` has to point to `input_len` readable bytes (it can be NULL when `input_len` is 0),
+/// `out` to `*out_len` writable bytes and `out_len` to a writable `size_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    out: *mut u8,
+    out_len: *mut usize,
+) -> i32 {
+    if out.is_null() || out_len.is_null() || (input.is_null() && input_len > 0) {
+        return Status::NullPointer as i32;
+    }
+    let input = match input_len {
+        0 => &[][..],
+        // SAFETY: the caller promises input_len readable bytes at input
+        _ => unsafe { slice::from_raw_parts(input, input_len) },
+    };
+
+    let (status, text) = solve(day, part, input);
+    // SAFETY: the caller promises that out_len points to a writable size_t
+    let capacity = unsafe { *out_len };
+    let needed = text.len() + 1;
+    if capacity < needed {
+        unsafe { *out_len = needed };
+        return Status::BufferTooSmall as i32;
+    }
+    // SAFETY: the caller promises out_len writable bytes at out, and the text with its NUL fits
+    unsafe {
+        let out = slice::from_raw_parts_mut(out, needed);
+        out[..text.len()].copy_from_slice(text.as_bytes());
+        out[text.len()] = 0;
+        *out_len = text.len();
+    }
+    status as i32
+}
+
+/// The C header of the library. `include/aoc.h` is a copy, which the tests compare with this
+/// and rewrite when `AOC_UPDATE_HEADER` is set.
+pub fn header() -> String {
+    let mut header = String::from(
+        "/* Generated by aoc_ffi::header, do not edit. Rerun the tests of aoc_ffi with\n \
+         * AOC_UPDATE_HEADER=1 to update it. */\n\
+         #ifndef AOC_H\n\
+         #define AOC_H\n\
+         \n\
+         #include <stddef.h>\n\
+         #include <stdint.h>\n\
+         \n\
+         #ifdef __cplusplus\n\
+         extern \"C\" {\n\
+         #endif\n\
+         \n",
+    );
+    for status in Status::ALL {
+        header.push_str(&format!(
+            "/* {} */\n#define {} {}\n",
+            status.description(),
+            status.name(),
+            status as i32
+        ));
+    }
+    header.push_str(
+        "\n\
+         /* Solves the part of the day on the input_len bytes at input. *out_len is the size of\n \
+         * out when called. The answer, or the error message, is written to out with a\n \
+         * terminating NUL and *out_len is set to its length without the NUL. When it does not\n \
+         * fit, nothing is written and *out_len is set to the size of the buffer it needs.\n \
+         * Returns AOC_OK or one of the errors above. */\n\
+         int32_t aoc_solve(uint8_t day, uint8_t part, const uint8_t *input, size_t input_len,\n                  \
+         uint8_t *out, size_t *out_len);\n\
+         \n\
+         #ifdef __cplusplus\n\
+         }\n\
+         #endif\n\
+         \n\
+         #endif /* AOC_H */\n",
+    );
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+    use std::ptr;
+
+    use super::*;
+
+    #[test]
+    fn check_header_is_up_to_date() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/aoc.h");
+        if env::var_os("AOC_UPDATE_HEADER").is_some() {
+            fs::write(&path, header()).unwrap();
+        }
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            header(),
+            "include/aoc.h is out of date, rerun the tests with AOC_UPDATE_HEADER=1"
+        );
+    }
+
+    fn call(day: u8, part: u8, input: &str, capacity: usize) -> (i32, String, usize) {
+        let mut out = vec![0xff; capacity];
+        let mut out_len = capacity;
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                &mut out_len,
+            )
+        };
+        let text = out
+            .iter()
+            .take_while(|byte| **byte != 0 && **byte != 0xff)
+            .map(|byte| *byte as char)
+            .collect();
+        (status, text, out_len)
+    }
+
+    #[test]
+    fn check_solve() {
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        assert_eq!(call(1, 2, input, 64), (0, String::from("6"), 1));
+        assert_eq!(
+            call(1, 2, input, 1),
+            (Status::BufferTooSmall as i32, String::new(), 2)
+        );
+
+        let (status, message, _) = call(1, 1, "L68\nX30", 128);
+        assert_eq!(status, Status::ParseError as i32);
+        assert!(message.starts_with("2:1: "));
+        assert_eq!(call(25, 1, "", 64).0, Status::UnsupportedDay as i32);
+        assert_eq!(call(12, 2, "", 64).0, Status::UnsupportedPart as i32);
+
+        let mut out_len = 0;
+        let status = unsafe { aoc_solve(1, 1, ptr::null(), 3, ptr::null_mut(), &mut out_len) };
+        assert_eq!(status, Status::NullPointer as i32);
+    }
+}
@@ -0,0 +1,52 @@
+/* Calls the library through include/aoc.h the way a C test rig would. Run by tests/c_abi.rs,
+ * which compiles it against the freshly built library. */
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static int failures = 0;
+
+static void check(int condition, const char *description) {
+    if (!condition) {
+        fprintf(stderr, "FAILED: %s\n", description);
+        failures++;
+    }
+}
+
+int main(void) {
+    const char *example = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+    uint8_t out[256];
+    size_t out_len = sizeof(out);
+
+    int32_t status = aoc_solve(1, 1, (const uint8_t *)example, strlen(example), out, &out_len);
+    check(status == AOC_OK, "day 1 part 1 is solved");
+    check(out_len == 1 && strcmp((const char *)out, "3") == 0, "day 1 part 1 answers 3");
+
+    out_len = sizeof(out);
+    status = aoc_solve(1, 2, (const uint8_t *)example, strlen(example), out, &out_len);
+    check(status == AOC_OK && strcmp((const char *)out, "6") == 0, "day 1 part 2 answers 6");
+
+    const char *wrong = "L68\nX30\n";
+    out_len = sizeof(out);
+    status = aoc_solve(1, 1, (const uint8_t *)wrong, strlen(wrong), out, &out_len);
+    check(status == AOC_PARSE_ERROR, "a wrong input is a parse error");
+    check(strncmp((const char *)out, "2:1: ", 5) == 0, "the parse error points to line 2");
+
+    out_len = 1;
+    status = aoc_solve(1, 1, (const uint8_t *)example, strlen(example), out, &out_len);
+    check(status == AOC_BUFFER_TOO_SMALL && out_len == 2, "a small buffer asks for 2 bytes");
+
+    out_len = sizeof(out);
+    check(aoc_solve(25, 1, NULL, 0, out, &out_len) == AOC_UNSUPPORTED_DAY, "day 25 is not solved");
+    out_len = sizeof(out);
+    check(aoc_solve(12, 2, NULL, 0, out, &out_len) == AOC_UNSUPPORTED_PART,
+          "day 12 has no part 2");
+    check(aoc_solve(1, 1, NULL, 3, out, &out_len) == AOC_NULL_POINTER, "NULL input is refused");
+
+    if (failures > 0) {
+        return 1;
+    }
+    printf("All checks passed\n");
+    return 0;
+}
@@ -0,0 +1,50 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+// Compiles tests/c/check_aoc.c with the C compiler ($CC or cc) against include/aoc.h and the
+// cdylib which cargo built for these tests, and runs it. The program exits with an error when
+// any of its checks fails.
+
+// Cargo builds the cdylib for the tests into the deps folder, next to the test binaries
+fn library_dir() -> PathBuf {
+    let test_binary = env::current_exe().unwrap();
+    test_binary.parent().unwrap().to_path_buf()
+}
+
+#[test]
+fn check_c_program() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let library_dir = library_dir();
+    let program = env::temp_dir().join(format!("aoc-ffi-test-{}", process::id()));
+    let compiler = env::var("CC").unwrap_or_else(|_| String::from("cc"));
+
+    let compiled = Command::new(&compiler)
+        .arg(manifest_dir.join("tests/c/check_aoc.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(&library_dir)
+        .arg("-laoc_ffi")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .unwrap_or_else(|error| panic!("Cannot run the C compiler {}: {}", compiler, error));
+    assert!(compiled.success(), "check_aoc.c does not compile");
+
+    let output = Command::new(&program)
+        .env("LD_LIBRARY_PATH", &library_dir)
+        .env("DYLD_LIBRARY_PATH", &library_dir)
+        .output()
+        .unwrap();
+    let _ = std::fs::remove_file(&program);
+    assert!(
+        output.status.success(),
+        "check_aoc failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "All checks passed\n"
+    );
+}
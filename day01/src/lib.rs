@@ -0,0 +1,233 @@
+//! Day 1: Secret Entrance. The safe dial has 100 positions and starts at 50, the input is a
+//! list of rotations, one per line, like `L68` or `R48`.
+
+use aoc_common::ParseError;
+use aoc_common::checked;
+use aoc_common::error::{parse_lines, parse_number};
+use aoc_common::recorder::{self, Event};
+use log::trace;
+
+/// Number of clicks the dial turns, negative numbers turn it left (L) and positive right (R).
+pub type Rotation = i32;
+
+/// Parses a single rotation like `L68`.
+pub fn parse_instruction(instruction: &str) -> Result<Rotation, ParseError> {
+    let Some(direction_string) = instruction.get(0..1) else {
+        return Err(ParseError::at_token(
+            instruction,
+            instruction,
+            "expected a rotation starting with L or R",
+        ));
+    };
+    let value_string = &instruction[1..];
+
+    let multiplier = match direction_string {
+        "L" => -1,
+        "R" => 1,
+        _ => {
+            return Err(ParseError::at_token(
+                instruction,
+                direction_string,
+                "expected direction L or R",
+            ));
+        }
+    };
+    // The sign is given by the direction, the distance is only digits
+    if !value_string.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(ParseError::at_token(
+            instruction,
+            value_string,
+            "expected a distance",
+        ));
+    }
+    let distance = parse_number::<u32>(instruction, value_string)?;
+    let Ok(parsed_value) = i32::try_from(distance) else {
+        return Err(ParseError::at_token(
+            instruction,
+            value_string,
+            &format!("expected a distance of at most {}", i32::MAX),
+        ));
+    };
+
+    Ok(parsed_value * multiplier)
+}
+
+/// Parses the rotations of the whole input, one per line.
+pub fn parse(input: &str) -> Result<Vec<Rotation>, ParseError> {
+    parse_lines(input, parse_instruction)
+}
+
+/// The dial of the safe, turned one rotation at a time, so the rotations do not need to be
+/// kept in memory. It counts both the rotations which stop at 0 and the clicks pointing at 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Dial {
+    position: i32,
+    zero_count: u32,
+    // Wider than the rotations, every rotation can add millions of clicks
+    clicks_count: i64,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial {
+            position: 50,
+            zero_count: 0,
+            clicks_count: 0,
+        }
+    }
+}
+
+impl Dial {
+    pub fn rotate(&mut self, rotation: Rotation) {
+        trace!(
+            "Instruction: {}, current position: {}",
+            rotation, self.position
+        );
+        let previous_position = self.position;
+
+        self.count_clicks(i64::from(rotation.abs() / 100));
+
+        self.position += rotation % 100;
+
+        if self.position == 0 {
+            if previous_position != 0 {
+                self.count_clicks(1);
+            }
+        } else if self.position < 0 {
+            self.position += 100;
+            if previous_position > 0 {
+                self.count_clicks(1);
+            }
+        } else if self.position > 0 {
+            if self.position > 99 {
+                self.position -= 100;
+                self.count_clicks(1);
+            }
+            if previous_position < 0 {
+                self.count_clicks(1);
+            }
+        }
+
+        if self.position == 0 {
+            self.zero_count = checked::add(self.zero_count, 1, "count of the stops at 0");
+        }
+
+        trace!(
+            "New position: {}, zero count: {}, clicks count: {}",
+            self.position, self.zero_count, self.clicks_count
+        );
+        recorder::record(|| {
+            Event::new("rotate")
+                .with("rotation", rotation)
+                .with("position", self.position)
+                .with("zeros", self.zero_count)
+                .with("clicks", self.clicks_count)
+        });
+    }
+
+    fn count_clicks(&mut self, clicks: i64) {
+        self.clicks_count = checked::add(self.clicks_count, clicks, "count of the clicks at 0");
+    }
+
+    pub fn position(&self) -> i32 {
+        self.position
+    }
+
+    /// Number of rotations which stopped at 0, the answer of part one.
+    pub fn zero_count(&self) -> u32 {
+        self.zero_count
+    }
+
+    /// Number of clicks at which the dial pointed at 0, the answer of part two.
+    pub fn clicks_count(&self) -> i64 {
+        self.clicks_count
+    }
+}
+
+fn turn(rotations: &[Rotation]) -> Dial {
+    let mut dial = Dial::default();
+    for rotation in rotations.iter().copied() {
+        dial.rotate(rotation);
+    }
+    dial
+}
+
+/// Counts how many times the dial stops at 0 after a rotation.
+pub fn solve_part_one(rotations: &[Rotation]) -> u32 {
+    turn(rotations).zero_count()
+}
+
+/// Counts every click at which the dial points at 0, including the clicks in the middle of
+/// a rotation.
+pub fn solve_part_two(rotations: &[Rotation]) -> i64 {
+    turn(rotations).clicks_count()
+}
+
+/// Solves both parts in a single pass over the lines of the input, which are parsed and
+/// forgotten one at a time, so any number of rotations fits in constant memory.
+pub fn solve_streaming<I>(lines: I) -> Result<(u32, i64), ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut dial = Dial::default();
+    for (index, line) in lines.into_iter().enumerate() {
+        let rotation =
+            parse_instruction(line.as_ref()).map_err(|error| error.on_line(index + 1))?;
+        dial.rotate(rotation);
+    }
+    Ok((dial.zero_count(), dial.clicks_count()))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_solution_part_two() {
+        check_examples(README, 2, |example| {
+            solve_part_two(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_streaming() {
+        check_examples(README, 1, |example| {
+            solve_streaming(example.input.lines()).unwrap().0
+        });
+        check_examples(README, 2, |example| {
+            solve_streaming(example.input.lines()).unwrap().1
+        });
+
+        let error = solve_streaming(["L68", "X30"]).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn check_invalid_instruction() {
+        let error = parse("L68\nX30").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.token, "X");
+
+        let error = parse_instruction("R4a").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (2, "4a"));
+
+        let error = parse_instruction("L-5").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (2, "-5"));
+        assert!(parse_instruction("R+5").is_err());
+        let error = parse_instruction("R2147483648").unwrap_err();
+        assert_eq!(error.message, "expected a distance of at most 2147483647");
+        assert_eq!(parse_instruction("L2147483647"), Ok(-i32::MAX));
+    }
+}
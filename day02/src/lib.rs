@@ -0,0 +1,166 @@
+//! Day 2: Gift Shop. The input holds comma separated ranges of product IDs like `11-22`,
+//! the solvers sum the invalid IDs, which are made of a repeated sequence of digits.
+
+use aoc_common::ParseError;
+use aoc_common::checked;
+use aoc_common::error::{parse_lines, parse_number};
+use log::debug;
+
+/// Inclusive range of product IDs, `Range(from, to)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Range(pub u64, pub u64);
+
+/// Parses a single line of comma separated ranges.
+pub fn parse_ranges(ranges: &str) -> Result<Vec<Range>, ParseError> {
+    let mut parsed_ranges: Vec<Range> = Vec::new();
+    // Long lists are wrapped after a comma, so a line can end with one
+    let line_ranges = ranges.strip_suffix(',').unwrap_or(ranges);
+    for range in line_ranges.split(',') {
+        let ids: Vec<&str> = range.split('-').collect();
+        if ids.len() != 2 {
+            return Err(ParseError::at_token(
+                ranges,
+                range,
+                "expected a range in format FROM-TO",
+            ));
+        }
+        let parsed_range = Range(
+            parse_number::<u64>(ranges, ids[0])?,
+            parse_number::<u64>(ranges, ids[1])?,
+        );
+        if parsed_range.0 > parsed_range.1 {
+            return Err(ParseError::at_token(
+                ranges,
+                range,
+                "expected the range to end at or after its start",
+            ));
+        }
+        parsed_ranges.push(parsed_range);
+    }
+    Ok(parsed_ranges)
+}
+
+/// Parses the ranges of all the lines of the input.
+pub fn parse(input: &str) -> Result<Vec<Range>, ParseError> {
+    Ok(parse_lines(input, parse_ranges)?.concat())
+}
+
+/// Sums the IDs made of a sequence of digits repeated twice.
+pub fn solve_part_one(ranges: &[Range]) -> u64 {
+    let mut solution: u64 = 0;
+    ranges.iter().for_each(|range| {
+        // An inclusive range, so a range ending at u64::MAX does not overflow
+        for i in range.0..=range.1 {
+            let i_as_string = i.to_string();
+
+            // We do not need to check numbers with odd length
+            if i_as_string.len() % 2 == 1 {
+                continue;
+            }
+
+            // We know this is whole number
+            let half_index = i_as_string.len() / 2;
+            let first_half = &i_as_string[0..half_index];
+            let second_half = &i_as_string[half_index..];
+            if first_half == second_half {
+                solution = checked::add(solution, i, "sum of the invalid IDs");
+            }
+        }
+    });
+    solution
+}
+
+/// Sums the IDs made of a sequence of digits repeated at least twice.
+pub fn solve_part_two(ranges: &[Range]) -> u64 {
+    let mut solution: u64 = 0;
+    ranges.iter().for_each(|range| {
+        debug!("Checking range {} - {}", range.0, range.1);
+        for i in range.0..=range.1 {
+            let i_as_string = i.to_string();
+
+            let mut is_invalid_id = false;
+            let i_length = i_as_string.len();
+            'delimiter_loop: for n in 1..(i_length / 2 + 1) {
+                // If the string is not divisible by the number of characters into equal chunks we skip the delimiter
+                if i_as_string.len() % n != 0 {
+                    continue;
+                }
+
+                let mut previous_slice = &i_as_string[0..n];
+                let mut start = n;
+                let mut end = n * 2;
+                while end <= i_length {
+                    let slice = &i_as_string[start..end];
+                    // If we found a slice which does not equal previous slice, we test next delimiter
+                    if slice != previous_slice {
+                        continue 'delimiter_loop;
+                    }
+                    previous_slice = slice;
+                    start = end;
+                    end += n;
+                }
+                // If we actualy got here, it means that the loop above never encountered different slice, that
+                // means the chunks are repeating and we found invalid ID, so we can stop searching for other delimiters
+                is_invalid_id = true;
+                break 'delimiter_loop;
+            }
+
+            if is_invalid_id {
+                solution = checked::add(solution, i, "sum of the invalid IDs");
+            }
+        }
+    });
+    solution
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_solution_part_two() {
+        check_examples(README, 2, |example| {
+            solve_part_two(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_large_ids() {
+        let last = [Range(u64::MAX - 1, u64::MAX)];
+        assert_eq!(solve_part_one(&last), 0);
+        assert_eq!(solve_part_two(&last), 0);
+
+        let big_ids = [
+            Range(18446744061844674406, 18446744061844674406),
+            Range(18446744071844674407, 18446744071844674407),
+        ];
+        let overflow = checked::run_checked(|| solve_part_one(&big_ids)).unwrap_err();
+        assert_eq!(overflow.step, "sum of the invalid IDs");
+        assert!(checked::run_checked(|| solve_part_two(&big_ids)).is_err());
+    }
+
+    #[test]
+    fn check_invalid_range() {
+        let error = parse_ranges("11-22,95-1x5").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (10, "1x5"));
+
+        let error = parse_ranges("11-22,50-10").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (7, "50-10"));
+        assert_eq!(parse_ranges("7-7"), Ok(vec![Range(7, 7)]));
+
+        let error = parse("11-22\n11-22,95").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.token, "95");
+    }
+}
@@ -0,0 +1,181 @@
+//! Day 3: Lobby. Every line of the input is a bank of batteries, every digit is the joltage
+//! of one battery. The solvers turn on batteries to get the highest joltage from every bank.
+
+use std::char;
+
+use aoc_common::ParseError;
+use aoc_common::checked;
+use aoc_common::error::parse_lines;
+use log::debug;
+
+/// Joltages of the batteries in one bank, in the order of the input.
+pub type Bank = Vec<u32>;
+
+// Part two turns on 12 batteries in every bank, so shorter banks cannot be solved
+const MINIMUM_BANK_LENGTH: usize = 12;
+
+/// Parses a single bank like `987654321111111`.
+pub fn line_to_bank(line: &str) -> Result<Bank, ParseError> {
+    let bank = line
+        .char_indices()
+        .map(|(index, char)| {
+            char.to_digit(10).ok_or_else(|| {
+                let token = &line[index..index + char.len_utf8()];
+                ParseError::at_token(line, token, "expected a battery joltage digit")
+            })
+        })
+        .collect::<Result<Vec<u32>, ParseError>>()?;
+
+    if bank.len() < MINIMUM_BANK_LENGTH {
+        return Err(ParseError::at_token(
+            line,
+            line,
+            "expected a bank of at least 12 batteries",
+        ));
+    }
+
+    Ok(bank)
+}
+
+/// Parses all the banks of the input, one per line.
+pub fn parse(input: &str) -> Result<Vec<Bank>, ParseError> {
+    parse_lines(input, line_to_bank)
+}
+
+/// Highest joltage of the bank with two batteries turned on.
+pub fn two_battery_joltage(bank: &[u32]) -> u32 {
+    let mut highest_joltage = 0;
+    let mut first_number_index = 0;
+    bank.iter().enumerate().for_each(|(i, battery)| {
+        if i == (bank.len() - 1) || highest_joltage >= *battery {
+            return;
+        };
+        highest_joltage = *battery;
+        first_number_index = i;
+    });
+
+    highest_joltage = 0;
+    let mut second_number_index = 0;
+    bank.iter().enumerate().for_each(|(i, battery)| {
+        if i <= first_number_index || highest_joltage >= *battery {
+            return;
+        };
+        highest_joltage = *battery;
+        second_number_index = i;
+    });
+
+    let joltage = bank[first_number_index] * 10 + bank[second_number_index];
+
+    debug!("Joltage {}", joltage);
+
+    joltage
+}
+
+/// Highest joltage of the bank with twelve batteries turned on.
+pub fn twelve_battery_joltage(bank: &[u32]) -> u64 {
+    let bank_length = bank.len();
+
+    let mut joltage = String::new();
+    let mut previous_highest_index: i32 = -1;
+    for n in 0..12 {
+        let mut highest_joltage = 0;
+        bank.iter().enumerate().for_each(|(i, battery)| {
+            let battery_value = *battery;
+            let index = i as i32;
+            if index <= previous_highest_index
+                || i >= (bank_length - 11 + n)
+                || highest_joltage >= battery_value
+            {
+                return;
+            };
+
+            highest_joltage = battery_value;
+            previous_highest_index = index;
+        });
+        joltage.push(char::from_digit(highest_joltage, 10).unwrap());
+    }
+
+    joltage.parse::<u64>().unwrap()
+}
+
+/// Sums the highest joltages of the banks with two batteries turned on.
+pub fn solve_part_one(banks: &[Bank]) -> u32 {
+    checked::sum(
+        banks.iter().map(|bank| two_battery_joltage(bank)),
+        "sum of the joltages",
+    )
+}
+
+/// Sums the highest joltages of the banks with twelve batteries turned on.
+pub fn solve_part_two(banks: &[Bank]) -> u64 {
+    checked::sum(
+        banks.iter().map(|bank| twelve_battery_joltage(bank)),
+        "sum of the joltages",
+    )
+}
+
+/// Solves both parts in a single pass over the lines of the input. Only the bank of the current
+/// line is kept in memory.
+pub fn solve_streaming<I>(lines: I) -> Result<(u32, u64), ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut solution = (0, 0);
+    for (index, line) in lines.into_iter().enumerate() {
+        let bank = line_to_bank(line.as_ref()).map_err(|error| error.on_line(index + 1))?;
+        solution.0 = checked::add(
+            solution.0,
+            two_battery_joltage(&bank),
+            "sum of the joltages",
+        );
+        solution.1 = checked::add(
+            solution.1,
+            twelve_battery_joltage(&bank),
+            "sum of the joltages",
+        );
+    }
+    Ok(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_solution_part_two() {
+        check_examples(README, 2, |example| {
+            solve_part_two(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_streaming() {
+        check_examples(README, 1, |example| {
+            solve_streaming(example.input.lines()).unwrap().0
+        });
+        check_examples(README, 2, |example| {
+            solve_streaming(example.input.lines()).unwrap().1
+        });
+    }
+
+    #[test]
+    fn check_invalid_bank() {
+        let error = line_to_bank("98765x321111111").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (6, "x"));
+
+        let error = line_to_bank("987").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (1, "987"));
+    }
+}
@@ -0,0 +1,129 @@
+//! Day 4: Printing Department. The input is a map of the paper rolls (`@`) and empty space (`.`),
+//! forklifts can only reach the rolls with fewer than four rolls around them.
+
+use aoc_common::ParseError;
+use aoc_common::grid::{Grid, Neighbourhood, Position, Symbols};
+use aoc_common::recorder::{self, Event};
+
+/// The map, `true` is a roll of paper.
+pub type Map = Grid<bool>;
+
+/// Characters of the map: `@` is a roll of paper and `.` empty space.
+pub const SYMBOLS: Symbols<'static, bool> = &[('@', true), ('.', false)];
+
+/// Parses the whole map, one row per line like `..@@.@@@@.`.
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Grid::parse(input, SYMBOLS)
+}
+
+/// Whether the cell is a roll with fewer than four rolls around it.
+pub fn is_accessible(map: &Map, position: Position) -> bool {
+    map[position]
+        && map
+            .neighbour_cells(position, Neighbourhood::Adjacent)
+            .filter(|is_roll| **is_roll)
+            .count()
+            < 4
+}
+
+fn position_event(name: &str, position: Position) -> Event {
+    Event::new(name)
+        .with("row", position.row)
+        .with("column", position.column)
+}
+
+/// Counts the rolls which the forklifts can reach.
+pub fn solve_part_one(map: &Map) -> u32 {
+    map.positions()
+        .filter(|position| is_accessible(map, *position))
+        .inspect(|position| recorder::record(|| position_event("accessible", *position)))
+        .count() as u32
+}
+
+/// Removes the accessible rolls over and over, every item is one wave: the rolls which were
+/// accessible at the same time and got removed at once. It ends when no roll is accessible.
+#[derive(Clone, Debug)]
+pub struct Removal {
+    map: Map,
+    removed: u32,
+}
+
+impl Removal {
+    pub fn new(map: Map) -> Self {
+        Removal { map, removed: 0 }
+    }
+
+    /// The map without the rolls removed so far.
+    pub fn map(&self) -> &Map {
+        &self.map
+    }
+}
+
+impl Iterator for Removal {
+    type Item = Vec<Position>;
+
+    fn next(&mut self) -> Option<Vec<Position>> {
+        let removed_rolls: Vec<Position> = self
+            .map
+            .positions()
+            .filter(|position| is_accessible(&self.map, *position))
+            .collect();
+        if removed_rolls.is_empty() {
+            return None;
+        }
+
+        for position in removed_rolls.iter() {
+            self.map[*position] = false;
+            recorder::record(|| position_event("remove", *position));
+        }
+        self.removed += removed_rolls.len() as u32;
+        recorder::record(|| {
+            Event::new("wave")
+                .with("removed", removed_rolls.len())
+                .with("total", self.removed)
+        });
+        Some(removed_rolls)
+    }
+}
+
+/// Counts the rolls which can be removed when the reachable rolls are removed over and over.
+pub fn solve_part_two(map: &Map) -> u32 {
+    Removal::new(map.clone())
+        .map(|removed_rolls| removed_rolls.len() as u32)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_solution_part_two() {
+        check_examples(README, 2, |example| {
+            solve_part_two(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_invalid_map_line() {
+        let error = parse("..@@.@@@@.\n..@@.#@@@.").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 6, "#")
+        );
+
+        let error = parse("..@@.@@@@.\n..@@").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+}
@@ -0,0 +1,240 @@
+//! Day 5: Cafeteria. The input is a database of fresh ingredient ID ranges followed by the
+//! IDs of the available ingredients.
+
+use aoc_common::ParseError;
+use aoc_common::checked;
+use aoc_common::error::parse_number;
+
+/// Inclusive range of fresh ingredient IDs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Range {
+    pub from: u64,
+    pub to: u64,
+}
+
+/// The database: fresh ranges and the available ingredient IDs, separated by an empty line.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Inventory {
+    pub ranges: Vec<Range>,
+    pub ingredient_ids: Vec<u64>,
+}
+
+/// Parses a single range like `3-5`, the range cannot end before it starts.
+pub fn parse_range_line(line: &str) -> Result<Range, ParseError> {
+    let parts: Vec<&str> = line.split('-').collect();
+    let [from_string, to_string] = parts[..] else {
+        let token = parts.get(2).copied().unwrap_or(line);
+        return Err(ParseError::at_token(
+            line,
+            token,
+            "expected a range in format FROM-TO",
+        ));
+    };
+
+    let range = Range {
+        from: parse_number::<u64>(line, from_string)?,
+        to: parse_number::<u64>(line, to_string)?,
+    };
+    if range.from > range.to {
+        return Err(ParseError::at_token(
+            line,
+            to_string,
+            "expected the range to end at or after its start",
+        ));
+    }
+    Ok(range)
+}
+
+/// Parses a single ingredient ID.
+pub fn parse_ingredient_id_line(line: &str) -> Result<u64, ParseError> {
+    parse_number::<u64>(line, line)
+}
+
+/// Parses the whole database, the ranges up to the first empty line and the IDs after it.
+pub fn parse(input: &str) -> Result<Inventory, ParseError> {
+    let mut inventory = Inventory::default();
+    let mut switch_conversion = false;
+    for (index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            switch_conversion = true;
+            continue;
+        }
+
+        if switch_conversion {
+            let ingredient_id =
+                parse_ingredient_id_line(line).map_err(|error| error.on_line(index + 1))?;
+            inventory.ingredient_ids.push(ingredient_id);
+        } else {
+            let range = parse_range_line(line).map_err(|error| error.on_line(index + 1))?;
+            inventory.ranges.push(range);
+        }
+    }
+    Ok(inventory)
+}
+
+/// Whether any of the fresh ranges contains the ingredient.
+pub fn is_fresh(ranges: &[Range], ingredient_id: u64) -> bool {
+    ranges
+        .iter()
+        .any(|range| ingredient_id >= range.from && ingredient_id <= range.to)
+}
+
+/// Counts the available ingredients which are fresh.
+pub fn solve_part_one(inventory: &Inventory) -> u32 {
+    let fresh_ids = inventory
+        .ingredient_ids
+        .iter()
+        .filter(|ingredient_id| is_fresh(&inventory.ranges, **ingredient_id))
+        .map(|_| 1);
+    checked::sum(fresh_ids, "count of the fresh ingredients")
+}
+
+/// Counts all the IDs which the fresh ranges cover.
+pub fn solve_part_two(inventory: &Inventory) -> u64 {
+    count_fresh_ids(&inventory.ranges)
+}
+
+/// Counts all the IDs which the ranges cover, overlapping ranges are merged first.
+pub fn count_fresh_ids(ranges: &[Range]) -> u64 {
+    let mut solution: u64 = 0;
+
+    let mut ranges_to_process: Vec<Range> = ranges.to_vec();
+    while !ranges_to_process.is_empty() {
+        let maybe_current_range = ranges_to_process.pop();
+        let Some(current_range) = maybe_current_range else {
+            break;
+        };
+
+        let mut merged = false;
+        ranges_to_process = ranges_to_process
+            .iter()
+            .map(|range| {
+                if current_range.from > range.to {
+                    return *range;
+                }
+                if current_range.to < range.from {
+                    return *range;
+                }
+
+                merged = true;
+
+                let mut new_range = *range;
+
+                if current_range.from < range.from {
+                    new_range.from = current_range.from;
+                }
+
+                if current_range.to > range.to {
+                    new_range.to = current_range.to;
+                }
+
+                new_range
+            })
+            .collect();
+
+        if !merged {
+            // println!(
+            //     "Adding range: {}-{} = {}",
+            //     current_range.from,
+            //     current_range.to,
+            //     current_range.to - current_range.from + 1
+            // );
+            // The range 0-18446744073709551615 covers one ID more than fits in u64
+            let length = checked::add(
+                current_range.to - current_range.from,
+                1,
+                "count of the fresh IDs",
+            );
+            solution = checked::add(solution, length, "count of the fresh IDs");
+        }
+    }
+
+    solution
+}
+
+/// Solves both parts in a single pass over the lines of the input. The fresh ranges are kept,
+/// every ID is checked against them, but the IDs themselves are never stored.
+pub fn solve_streaming<I>(lines: I) -> Result<(u32, u64), ParseError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut ranges = Vec::new();
+    let mut fresh_count: u32 = 0;
+    let mut switch_conversion = false;
+    for (index, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref();
+        if line.is_empty() {
+            switch_conversion = true;
+            continue;
+        }
+
+        if switch_conversion {
+            let ingredient_id =
+                parse_ingredient_id_line(line).map_err(|error| error.on_line(index + 1))?;
+            if is_fresh(&ranges, ingredient_id) {
+                fresh_count = checked::add(fresh_count, 1, "count of the fresh ingredients");
+            }
+        } else {
+            ranges.push(parse_range_line(line).map_err(|error| error.on_line(index + 1))?);
+        }
+    }
+    Ok((fresh_count, count_fresh_ids(&ranges)))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_solution_part_two() {
+        check_examples(README, 2, |example| {
+            solve_part_two(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_streaming() {
+        check_examples(README, 1, |example| {
+            solve_streaming(example.input.lines()).unwrap().0
+        });
+        check_examples(README, 2, |example| {
+            solve_streaming(example.input.lines()).unwrap().1
+        });
+    }
+
+    #[test]
+    fn check_invalid_lines() {
+        let error = parse_range_line("10-l4").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (4, "l4"));
+
+        let error = parse_range_line("1-3-9").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (5, "9"));
+        let error = parse_range_line("15-3").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (4, "3"));
+        let error = parse("5-3\n\n4").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(
+            solve_part_two(&parse("0-18446744073709551614").unwrap()),
+            u64::MAX
+        );
+
+        let error = parse("3-5\n\n1\n17 ").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (4, 1, "17 ")
+        );
+        assert_eq!(solve_streaming(["3-5", "", "1", "17 "]).unwrap_err(), error);
+    }
+}
@@ -0,0 +1,239 @@
+//! Day 6: Trash Compactor. The input is a math worksheet, the columns of numbers are problems
+//! and the last line holds their operations.
+
+use aoc_common::ParseError;
+use aoc_common::error::parse_number;
+use aoc_common::wide::Wide;
+
+/// Lines of the worksheet. The two parts read the numbers differently (part one by rows,
+/// part two by columns), so the lines are kept as they are, including their whitespace.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Worksheet {
+    pub number_lines: Vec<String>,
+    pub operations_line: String,
+}
+
+/// Splits the input into the lines of numbers and the operations on the last line.
+pub fn parse(input: &str) -> Result<Worksheet, ParseError> {
+    let mut number_lines: Vec<String> = input.lines().map(String::from).collect();
+    let Some(operations_line) = number_lines.pop() else {
+        return Err(ParseError::new(1, 1, "", "expected a line of operations"));
+    };
+    Ok(Worksheet {
+        number_lines,
+        operations_line,
+    })
+}
+
+fn parse_numbers_line_by_space(line: &str) -> Result<Vec<u64>, ParseError> {
+    let parts: Vec<&str> = line.split(" ").collect();
+
+    parts
+        .iter()
+        .filter(|part| !part.is_empty())
+        .map(|part| parse_number::<u64>(line, part))
+        .collect()
+}
+
+/// Sums the results of the problems with the numbers read by rows.
+pub fn solve_part_one(worksheet: &Worksheet) -> Result<Wide, ParseError> {
+    let number_lines = &worksheet.number_lines;
+    let operations_line = &worksheet.operations_line;
+    let operations = parse_operations_line(operations_line)
+        .map_err(|error| error.on_line(number_lines.len() + 1))?;
+
+    let mut numbers: Vec<Vec<u64>> = Vec::new();
+    for (index, line) in number_lines.iter().enumerate() {
+        let number_row =
+            parse_numbers_line_by_space(line).map_err(|error| error.on_line(index + 1))?;
+        if number_row.len() != operations.len() {
+            return Err(ParseError::at_token(
+                line,
+                line,
+                "expected one number for every operation",
+            )
+            .on_line(index + 1));
+        }
+        numbers.push(number_row);
+    }
+    if numbers.is_empty() {
+        return Err(ParseError::new(1, 1, "", "expected a line of numbers"));
+    }
+
+    let mut solution = Wide::zero();
+    let problems_count = operations.len();
+
+    for i in 0..problems_count {
+        let mut result = Wide::from(numbers[0][i]);
+        let operation = &operations[i];
+        for number_row in numbers.iter().skip(1) {
+            let number = Wide::from(number_row[i]);
+            match *operation {
+                Operation::Multiply => result *= &number,
+                Operation::Add => result += &number,
+            }
+        }
+        solution += &result;
+    }
+
+    Ok(solution)
+}
+
+/// Sums the results of the problems with the numbers read by columns, right to left.
+pub fn solve_part_two(worksheet: &Worksheet) -> Result<Wide, ParseError> {
+    let number_lines = &worksheet.number_lines;
+    let operations_line = &worksheet.operations_line;
+    let mut solution = Wide::zero();
+    if number_lines.is_empty() {
+        return Ok(solution);
+    }
+
+    let parsed_number_lines: Vec<Vec<char>> = number_lines
+        .iter()
+        .map(|line| line.chars().collect())
+        .collect();
+
+    let parsed_operations_line: Vec<char> = operations_line.chars().collect();
+
+    let numbers_count = parsed_number_lines[0].len();
+    let mut operation = Operation::Add;
+    let mut problem_numbers: Vec<Wide> = Vec::new();
+    for i in 0..numbers_count {
+        let mut number_string: String = String::new();
+        for (j, parsed_number_line) in parsed_number_lines.iter().enumerate() {
+            let Some(&number_char) = parsed_number_line.get(i) else {
+                return Err(ParseError::new(
+                    j + 1,
+                    i + 1,
+                    "",
+                    "expected the line to be as long as the first line",
+                ));
+            };
+            if number_char == ' ' {
+                continue;
+            }
+            if !number_char.is_ascii_digit() {
+                return Err(ParseError::new(
+                    j + 1,
+                    i + 1,
+                    &number_char.to_string(),
+                    "expected a digit or a space",
+                ));
+            }
+            number_string.push(number_char);
+        }
+        // Tall columns make numbers with more digits than fit in u64
+        let number = if !number_string.is_empty() {
+            number_string.parse::<Wide>().unwrap()
+        } else {
+            Wide::zero()
+        };
+
+        let operation_char = if parsed_operations_line.len() > i {
+            parsed_operations_line[i]
+        } else {
+            ' '
+        };
+
+        match operation_char {
+            ' ' => (),
+            '*' => operation = Operation::Multiply,
+            '+' => operation = Operation::Add,
+            _ => {
+                return Err(ParseError::new(
+                    number_lines.len() + 1,
+                    i + 1,
+                    &operation_char.to_string(),
+                    "expected operation * or +",
+                ));
+            }
+        }
+
+        if !number.is_zero() {
+            problem_numbers.push(number);
+        } else {
+            let result: Wide = match operation {
+                Operation::Multiply => problem_numbers.drain(..).product(),
+                Operation::Add => problem_numbers.drain(..).sum(),
+            };
+            solution += &result;
+        }
+    }
+
+    if !problem_numbers.is_empty() {
+        let result: Wide = match operation {
+            Operation::Multiply => problem_numbers.drain(..).product(),
+            Operation::Add => problem_numbers.drain(..).sum(),
+        };
+        solution += &result;
+    }
+
+    Ok(solution)
+}
+
+pub enum Operation {
+    Multiply,
+    Add,
+}
+
+fn parse_operations_line(line: &str) -> Result<Vec<Operation>, ParseError> {
+    let parts: Vec<&str> = line.split(" ").collect();
+
+    parts
+        .iter()
+        .filter(|part| !part.is_empty())
+        .map(|part| match *part {
+            "*" => Ok(Operation::Multiply),
+            "+" => Ok(Operation::Add),
+            _ => Err(ParseError::at_token(
+                line,
+                part,
+                "expected operation * or +",
+            )),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(&parse(&example.input).unwrap()).unwrap()
+        });
+    }
+
+    #[test]
+    fn check_solution_part_two() {
+        check_examples(README, 2, |example| {
+            solve_part_two(&parse(&example.input).unwrap()).unwrap()
+        });
+    }
+
+    #[test]
+    fn check_invalid_worksheet() {
+        let worksheet = parse("123 328\n 45 6x4\n*   +").unwrap();
+        let error = solve_part_one(&worksheet).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+
+        let error = solve_part_two(&worksheet).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 6, "x")
+        );
+
+        let worksheet = parse("123 328\n*   -").unwrap();
+        let error = solve_part_one(&worksheet).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 5, "-")
+        );
+        assert!(parse("").is_err());
+    }
+}
@@ -0,0 +1,279 @@
+//! Day 7: Laboratories. The input is a tachyon manifold, a beam enters it at `S` and moves
+//! down, every splitter (`^`) it hits splits it into a beam on the left and on the right.
+
+use std::collections::HashMap;
+
+use aoc_common::ParseError;
+use aoc_common::checked;
+use aoc_common::grid::{Grid, Symbols};
+use aoc_common::recorder::{self, Event};
+
+/// A cell of the manifold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cell {
+    Start,
+    Empty,
+    Splitter,
+}
+
+/// Characters of the manifold: the start of the beam `S`, empty space `.` and splitters `^`.
+pub const SYMBOLS: Symbols<'static, Cell> = &[
+    ('.', Cell::Empty),
+    ('^', Cell::Splitter),
+    ('S', Cell::Start),
+];
+
+/// Grid of a manifold which passed the checks of `parse`, the solvers rely on them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Manifold {
+    grid: Grid<Cell>,
+}
+
+impl Manifold {
+    pub fn grid(&self) -> &Grid<Cell> {
+        &self.grid
+    }
+}
+
+/// Parses the manifold, every line is one row. The grid makes sure every row is as wide as
+/// the first one, the solvers index the beams by its columns.
+pub fn parse(input: &str) -> Result<Manifold, ParseError> {
+    let grid = Grid::parse(input, SYMBOLS)?;
+    let Some(first_line) = input.lines().next() else {
+        return Err(ParseError::new(1, 1, "", "expected a manifold"));
+    };
+    if !grid.row(0).contains(&Cell::Start) {
+        return Err(ParseError::at_token(
+            first_line,
+            first_line,
+            "expected the start of the beam (S) on the first line",
+        ));
+    }
+    Ok(Manifold { grid })
+}
+
+fn starting_beams(grid: &Grid<Cell>) -> Vec<bool> {
+    grid.row(0)
+        .iter()
+        .map(|cell| *cell == Cell::Start)
+        .collect()
+}
+
+// Columns of the beams like 6,8 for the recorded events
+fn beam_columns(beams: &[bool]) -> String {
+    let columns: Vec<String> = beams
+        .iter()
+        .enumerate()
+        .filter(|(_, beam)| **beam)
+        .map(|(column, _)| column.to_string())
+        .collect();
+    columns.join(",")
+}
+
+/// The beams below a row of the manifold and the columns of the splitters they hit in it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BeamRow {
+    pub row: usize,
+    pub beams: Vec<bool>,
+    pub splits: Vec<usize>,
+}
+
+/// Moves the beam down the manifold one row at a time, starting with the row below `S`.
+pub struct BeamRows<'a> {
+    grid: &'a Grid<Cell>,
+    row: usize,
+    beams: Vec<bool>,
+}
+
+impl Iterator for BeamRows<'_> {
+    type Item = BeamRow;
+
+    fn next(&mut self) -> Option<BeamRow> {
+        self.row += 1;
+        if self.row >= self.grid.height() {
+            return None;
+        }
+        let row_index = self.row;
+        let beams = &mut self.beams;
+        let mut splits = Vec::new();
+
+        self.grid
+            .row(row_index)
+            .iter()
+            .enumerate()
+            .for_each(|(index, cell)| {
+                if *cell == Cell::Empty {
+                    return;
+                }
+                if !beams[index] {
+                    return;
+                }
+
+                splits.push(index);
+                recorder::record(|| {
+                    Event::new("split")
+                        .with("row", row_index)
+                        .with("column", index)
+                });
+
+                // Split the beam
+                beams[index] = false;
+                if index > 0 {
+                    beams[index - 1] = true;
+                }
+                if index < (beams.len() - 1) {
+                    beams[index + 1] = true;
+                }
+            });
+        recorder::record(|| {
+            Event::new("beams")
+                .with("row", row_index)
+                .with("columns", beam_columns(beams))
+        });
+
+        Some(BeamRow {
+            row: row_index,
+            beams: beams.clone(),
+            splits,
+        })
+    }
+}
+
+impl Manifold {
+    /// Follows the beam from `S` to the bottom of the manifold.
+    pub fn beam_rows(&self) -> BeamRows<'_> {
+        BeamRows {
+            grid: &self.grid,
+            row: 0,
+            beams: starting_beams(&self.grid),
+        }
+    }
+}
+
+/// Counts how many times the beam is split.
+pub fn solve_part_one(manifold: &Manifold) -> u64 {
+    manifold
+        .beam_rows()
+        .map(|beam_row| beam_row.splits.len() as u64)
+        .sum()
+}
+
+fn solve_row(
+    beams: &[bool],
+    row: usize,
+    grid: &Grid<Cell>,
+    solution_counts: &mut HashMap<(Vec<bool>, usize), u64>,
+) -> u64 {
+    let mut solutions: u64 = 0;
+    let key = (beams.to_vec(), row);
+
+    // If we already processed this branch before, just return the already calculated value, it's faster
+    if let Some(count) = solution_counts.get(&key) {
+        return *count;
+    }
+
+    // If we are on the last row, there is just one solution left
+    if row >= grid.height() {
+        solution_counts.insert(key, 1);
+        return 1;
+    }
+
+    // If we are not on the last row, we need to go through all the options
+    grid.row(row).iter().enumerate().for_each(|(index, cell)| {
+        // If beam is not hitting this cell, we do not need to do anything
+        if !beams[index] {
+            return;
+        }
+
+        // If the current cell is not a splitter, we jump to next row
+        if *cell == Cell::Empty {
+            let count = solve_row(beams, row + 1, grid, solution_counts);
+            solutions = checked::add(solutions, count, "count of the timelines");
+            return;
+        }
+
+        // Splitter hit
+
+        // Left branch
+        if index > 0 {
+            let mut left_beams = beams.to_vec();
+            left_beams[index] = false;
+            left_beams[index - 1] = true;
+            let count = solve_row(&left_beams, row + 1, grid, solution_counts);
+            solutions = checked::add(solutions, count, "count of the timelines");
+        }
+
+        // Right branch
+        if index < (beams.len() - 1) {
+            let mut right_beams = beams.to_vec();
+            right_beams[index] = false;
+            right_beams[index + 1] = true;
+            let count = solve_row(&right_beams, row + 1, grid, solution_counts);
+            solutions = checked::add(solutions, count, "count of the timelines");
+        }
+    });
+
+    recorder::record(|| {
+        Event::new("timelines")
+            .with("row", row)
+            .with("beams", beam_columns(beams))
+            .with("count", solutions)
+    });
+    solution_counts.insert(key, solutions);
+    solutions
+}
+
+/// Counts the timelines of a single particle, which takes either side at every splitter.
+pub fn solve_part_two(manifold: &Manifold) -> u64 {
+    let grid = manifold.grid();
+
+    let mut solution_counts: HashMap<(Vec<bool>, usize), u64> = HashMap::new();
+
+    solve_row(&starting_beams(grid), 1, grid, &mut solution_counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_solution_part_two() {
+        check_examples(README, 2, |example| {
+            solve_part_two(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_timelines_overflow() {
+        // Every row of splitters doubles the timelines, 70 of them do not fit into u64
+        let width = 141;
+        let mut rows = vec![format!("{:.^width$}", "S")];
+        rows.extend((0..70).map(|_| "^".repeat(width)));
+        let manifold = parse(&rows.join("\n")).unwrap();
+        let overflow = checked::run_checked(|| solve_part_two(&manifold)).unwrap_err();
+        assert_eq!(overflow.step, "count of the timelines");
+    }
+
+    #[test]
+    fn check_invalid_manifold() {
+        let error = parse("...S...\n..^.#..").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 5, "#")
+        );
+
+        let error = parse("...S...\n..^..").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+    }
+}
@@ -0,0 +1,268 @@
+//! Day 8: Playground. The input holds the positions of junction boxes, the closest pairs of
+//! boxes get connected into circuits.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::f64;
+use std::iter;
+
+use aoc_common::ParseError;
+use aoc_common::checked;
+use aoc_common::error::{parse_lines, parse_number};
+use aoc_common::recorder::{self, Event};
+use aoc_common::wide::Wide;
+
+/// Position of a junction box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Box {
+    pub x: u64,
+    pub y: u64,
+    pub z: u64,
+}
+
+pub struct Connection {
+    from: usize,
+    to: usize,
+    distance: f64,
+}
+
+/// Parses the position of a single junction box like `162,817,812`.
+pub fn parse_box(line: &str) -> Result<Box, ParseError> {
+    let part_strings: Vec<&str> = line.split(",").collect();
+    if part_strings.len() != 3 {
+        let token = part_strings.get(3).copied().unwrap_or(line);
+        return Err(ParseError::at_token(
+            line,
+            token,
+            "expected a position in format X,Y,Z",
+        ));
+    }
+    let parts: Vec<u64> = part_strings
+        .iter()
+        .map(|part| parse_number::<u64>(line, part))
+        .collect::<Result<Vec<u64>, ParseError>>()?;
+
+    Ok(Box {
+        x: parts[0],
+        y: parts[1],
+        z: parts[2],
+    })
+}
+
+/// Parses the positions of all the junction boxes, one per line.
+pub fn parse(input: &str) -> Result<Vec<Box>, ParseError> {
+    parse_lines(input, parse_box)
+}
+
+fn euclidean_distance(a: &Box, b: &Box) -> f64 {
+    let mut distance_squared: f64 = 0.0;
+    distance_squared += ((a.x as f64) - (b.x as f64)).powf(2.0);
+    distance_squared += ((a.y as f64) - (b.y as f64)).powf(2.0);
+    distance_squared += ((a.z as f64) - (b.z as f64)).powf(2.0);
+    distance_squared.sqrt()
+}
+
+fn calculate_distances(boxes: &[Box]) -> Vec<Connection> {
+    let mut connections: Vec<Connection> = Vec::new();
+    for i in 0..boxes.len() {
+        for j in i + 1..boxes.len() {
+            connections.push(Connection {
+                from: i,
+                to: j,
+                distance: euclidean_distance(&boxes[i], &boxes[j]),
+            });
+        }
+    }
+    connections.sort_by(|a, b| {
+        b.distance
+            .partial_cmp(&a.distance)
+            .unwrap_or(Ordering::Equal)
+    });
+    connections
+}
+
+fn add_connection_to_circuits(
+    connection: &Connection,
+    circuits: &[HashSet<usize>],
+) -> Vec<HashSet<usize>> {
+    let mut circuit_found = false;
+
+    let mut new_circuits: Vec<HashSet<usize>> = Vec::new();
+
+    let mut new_merge_circuit_index = usize::MAX;
+    let mut circuits_to_merge: Vec<HashSet<usize>> = Vec::new();
+
+    circuits.iter().for_each(|circuit| {
+        let mut from_found = false;
+        let mut to_found = false;
+        circuit.iter().for_each(|box_id| {
+            if *box_id == connection.from {
+                from_found = true;
+            }
+            if *box_id == connection.to {
+                to_found = true;
+            }
+        });
+
+        if from_found || to_found {
+            // If we already found another circuit with same connection, we have added the circuit there,
+            // so for additional ciruits including the same connection we will need those to be merged
+            // which is why we are not adding them to the new circuits array.
+            if circuit_found {
+                circuits_to_merge.push(circuit.clone());
+                return;
+            }
+            circuit_found = true;
+            new_merge_circuit_index = new_circuits.len();
+        }
+
+        let mut new_circuit = circuit.clone();
+
+        if !from_found && !to_found {
+            new_circuits.push(new_circuit);
+            return;
+        }
+
+        new_circuit.insert(connection.to);
+        new_circuit.insert(connection.from);
+
+        new_circuits.push(new_circuit);
+    });
+
+    // We have not found any curcuit for the connection, add it as new circuit
+    if !circuit_found {
+        new_circuits.push(HashSet::from([connection.from, connection.to]));
+    } else if !circuits_to_merge.is_empty() {
+        circuits_to_merge.iter().for_each(|circuit| {
+            circuit.iter().for_each(|box_id| {
+                new_circuits[new_merge_circuit_index].insert(*box_id);
+            });
+        });
+    }
+
+    new_circuits
+}
+
+fn record_connection(connection: &Connection, circuits: &[HashSet<usize>]) {
+    recorder::record(|| {
+        Event::new("connect")
+            .with("from", connection.from)
+            .with("to", connection.to)
+            .with("distance", connection.distance)
+            .with("circuits", circuits.len())
+            .with(
+                "largest",
+                circuits.iter().map(HashSet::len).max().unwrap_or(0),
+            )
+    });
+}
+
+/// Connects the `max_operations` closest pairs of boxes and multiplies the sizes of the three
+/// largest circuits. The puzzle input uses 1000 connections, the example 10.
+pub fn solve_part_one(boxes: &[Box], max_operations: usize) -> u64 {
+    let mut possible_connections = calculate_distances(boxes);
+    let mut circuits: Vec<HashSet<usize>> = Vec::new();
+    for _i in 0..max_operations {
+        let Some(shortest_connection) = possible_connections.pop() else {
+            break;
+        };
+        circuits = add_connection_to_circuits(&shortest_connection, &circuits);
+        record_connection(&shortest_connection, &circuits);
+    }
+
+    let mut circuit_lengts: Vec<usize> = circuits.iter().map(|c| c.len()).collect();
+    // Boxes which did not get connected are circuits of their own
+    let connected_boxes: usize = circuit_lengts.iter().sum();
+    circuit_lengts.extend(iter::repeat_n(1, boxes.len() - connected_boxes));
+
+    if circuit_lengts.len() < 3 {
+        return 0;
+    };
+
+    circuit_lengts.sort();
+
+    let mut solution: u64 = 1;
+    for _i in 0..3 {
+        if let Some(length) = circuit_lengts.pop() {
+            solution = checked::mul(solution, length as u64, "product of the circuit sizes");
+        };
+    }
+
+    solution
+}
+
+/// Connects the closest pairs until all the boxes are in one circuit and multiplies the X
+/// coordinates of the last connected pair.
+pub fn solve_part_two(boxes: &[Box]) -> Wide {
+    let mut possible_connections = calculate_distances(boxes);
+    let mut circuits: Vec<HashSet<usize>> = Vec::new();
+
+    let mut last_pair = None;
+    while circuits.len() != 1 || circuits[0].len() != boxes.len() {
+        let Some(shortest_connection) = possible_connections.pop() else {
+            break;
+        };
+        circuits = add_connection_to_circuits(&shortest_connection, &circuits);
+        record_connection(&shortest_connection, &circuits);
+        last_pair = Some((shortest_connection.from, shortest_connection.to));
+    }
+
+    // The coordinates are u64, so their product can need up to 128 bits
+    match last_pair {
+        Some((from, to)) => Wide::from(boxes[from].x) * Wide::from(boxes[to].x),
+        None => Wide::zero(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(
+                &parse(&example.input).unwrap(),
+                example.option("connections").unwrap_or(1000),
+            )
+        });
+    }
+
+    #[test]
+    fn check_unconnected_boxes() {
+        // Found by the differential tests: the boxes which are not connected yet are circuits
+        // of their own, so a single connection still gives three circuits
+        let boxes = parse("0,0,0\n1,0,0\n100,0,0\n300,0,0").unwrap();
+        assert_eq!(solve_part_one(&boxes, 1), 2);
+        assert_eq!(solve_part_one(&boxes[..2], 1), 0);
+    }
+
+    #[test]
+    fn check_solution_part_two() {
+        check_examples(README, 2, |example| {
+            solve_part_two(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_invalid_boxes() {
+        let error = parse("162,817,812\n57,618").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = parse("162,817,812,5").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (1, 13, "5")
+        );
+
+        let error = parse("162,-817,812").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (1, 5, "-817")
+        );
+    }
+}
@@ -0,0 +1,167 @@
+//! Day 9: Movie Theater. The input holds the positions of the red tiles, the solvers look for
+//! the biggest rectangle with red tiles in two of its opposite corners.
+
+use aoc_common::ParseError;
+use aoc_common::cancel::{self, Cancelled};
+use aoc_common::error::{parse_lines, parse_number};
+use geo::{Covers, LineString, Polygon};
+use log::debug;
+
+/// Position of a red tile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Point {
+    pub x: u64,
+    pub y: u64,
+}
+
+fn split_point_line(line: &str) -> Result<Vec<&str>, ParseError> {
+    let parts: Vec<&str> = line.split(',').collect();
+    if parts.len() != 2 {
+        let token = parts.get(2).copied().unwrap_or(line);
+        return Err(ParseError::at_token(
+            line,
+            token,
+            "expected a point in format X,Y",
+        ));
+    }
+    Ok(parts)
+}
+
+/// Parses the position of a single red tile like `7,1`.
+pub fn parse_point(line: &str) -> Result<Point, ParseError> {
+    let parts = split_point_line(line)?;
+    Ok(Point {
+        x: parse_number::<u64>(line, parts[0])?,
+        y: parse_number::<u64>(line, parts[1])?,
+    })
+}
+
+/// Parses the red tiles of the input, one per line. They are the corners of a polygon in the
+/// order of the input.
+pub fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+    parse_lines(input, parse_point)
+}
+
+/// Finds the biggest area of a rectangle with red tiles in two opposite corners.
+pub fn solve_part_one(points: &[Point]) -> u64 {
+    let mut biggest_area = 0;
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            let width = points[j].x.abs_diff(points[i].x) + 1;
+            let height = points[j].y.abs_diff(points[i].y) + 1;
+
+            let area = width * height;
+            if area > biggest_area {
+                debug!(
+                    "New biggest area found: {} (points: ({},{}) and ({},{})), width: {}, height: {}",
+                    area, points[i].x, points[i].y, points[j].x, points[j].y, width, height
+                );
+                biggest_area = area;
+            }
+        }
+    }
+
+    biggest_area
+}
+
+/// Finds the biggest area of such a rectangle which lies fully inside of the polygon of the
+/// red tiles. Stops with `Cancelled` when the runner cancels the solver.
+pub fn solve_part_two(points: &[Point]) -> Result<f64, Cancelled> {
+    let points: Vec<(f64, f64)> = points
+        .iter()
+        .map(|point| (point.x as f64, point.y as f64))
+        .collect();
+    let polygon = Polygon::new(LineString::from(points.clone()), vec![]);
+
+    let mut biggest_area = 0.0;
+    for i in 0..points.len() {
+        cancel::check()?;
+        for j in i + 1..points.len() {
+            let a = points[i];
+            let b = points[j];
+            // The whole outline of the rectangle has to be inside or on the border, the polygon
+            // cannot have holes so then the rectangle is inside as well
+            let rectangle_points = vec![(a.0, a.1), (a.0, b.1), (b.0, b.1), (b.0, a.1), (a.0, a.1)];
+            let rectangle_line_string = LineString::from(rectangle_points.clone());
+
+            // println!(
+            //     "Checking rectangle with corners: ({}, {}), ({}, {}), ({}, {}), ({}, {})",
+            //     a.0, a.1, a.0, b.1, b.0, a.1, b.0, b.1
+            // );
+
+            if !polygon.covers(&rectangle_line_string) {
+                // println!("Rectangle is not fully contained in the polygon.");
+                continue;
+            }
+
+            // println!("Rectangle is fully contained in the polygon.");
+
+            let width = (points[j].0 - points[i].0).abs() + 1.0;
+            let height = (points[j].1 - points[i].1).abs() + 1.0;
+
+            let area = width * height;
+            if area > biggest_area {
+                debug!(
+                    "New biggest area found: {} (points: ({},{}) and ({},{})), width: {}, height: {}",
+                    area, a.0, a.1, b.0, b.1, width, height
+                );
+                biggest_area = area;
+            }
+        }
+    }
+
+    Ok(biggest_area)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_solution_part_two() {
+        check_examples(README, 2, |example| {
+            solve_part_two(&parse(&example.input).unwrap()).unwrap()
+        });
+    }
+
+    #[test]
+    fn check_rectangle_across_a_notch() {
+        // Found by the differential tests: the rectangle from (0,0) to (4,4) has all its
+        // corners in the polygon but crosses the notch at the top, the biggest one is along
+        // the left border from (0,0) to (2,5)
+        let points = parse("0,0\n0,5\n2,5\n2,2\n4,2\n4,4\n6,4\n6,0").unwrap();
+        assert_eq!(solve_part_two(&points).unwrap(), 18.0);
+    }
+
+    #[test]
+    fn check_invalid_points() {
+        let error = parse("7,1\n11;1").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 1, "11;1")
+        );
+
+        let error = parse("7,1\n1,2,3").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 5, "3")
+        );
+
+        let error = parse("7,1\n11,y").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 4, "y")
+        );
+    }
+}
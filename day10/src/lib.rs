@@ -0,0 +1,160 @@
+//! Day 10: Factory. Every line of the input describes a machine with indicator lights, buttons
+//! and joltage requirements, the solvers look for the fewest button presses.
+
+use std::error::Error;
+use std::fmt;
+
+use aoc_common::ParseError;
+use aoc_common::cancel::Cancelled;
+use aoc_common::error::parse_lines;
+
+use crate::running_machine::{RunningMachine, solve_running_machine};
+use crate::simple_machine::{SimpleMachine, solve_simple_machine};
+
+mod parsing;
+mod running_machine;
+mod simple_machine;
+
+pub use crate::parsing::{Machine, parse_machine};
+
+/// Parses all the machines of the input, one per line.
+pub fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+    parse_lines(input, parse_machine)
+}
+
+/// Why the solvers have no answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MachineError {
+    /// The runner cancelled the solver.
+    Cancelled,
+    /// No presses of the buttons configure the machine, numbered from 1 like the lines.
+    Unreachable { machine: usize },
+}
+
+impl fmt::Display for MachineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MachineError::Cancelled => write!(f, "{}", Cancelled),
+            MachineError::Unreachable { machine } => {
+                write!(f, "no presses of the buttons configure machine {}", machine)
+            }
+        }
+    }
+}
+
+impl Error for MachineError {}
+
+impl From<Cancelled> for MachineError {
+    fn from(_: Cancelled) -> Self {
+        MachineError::Cancelled
+    }
+}
+
+/// Sums the fewest presses which turn on the indicator lights of every machine. Fails when a
+/// machine cannot be configured or the runner cancels the solver.
+pub fn solve_part_one(machines: &[Machine]) -> Result<u32, MachineError> {
+    let machines: Vec<SimpleMachine> = machines.iter().map(SimpleMachine::new).collect();
+
+    let mut solution = 0;
+
+    for (index, machine) in machines.iter().enumerate() {
+        solution += solve_simple_machine(machine)?
+            .ok_or(MachineError::Unreachable { machine: index + 1 })?;
+    }
+
+    Ok(solution)
+}
+
+/// Sums the fewest presses which bring the joltage counters of every machine to their
+/// requirements. Fails when a machine cannot be configured or the runner cancels the solver.
+pub fn solve_part_two(machines: &[Machine]) -> Result<usize, MachineError> {
+    let machines: Vec<RunningMachine> = machines.iter().map(RunningMachine::new).collect();
+
+    let mut solution = 0;
+
+    for (index, machine) in machines.iter().enumerate() {
+        solution += solve_running_machine(machine, index)?
+            .ok_or(MachineError::Unreachable { machine: index + 1 })?;
+    }
+
+    Ok(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::cancel::{CancelToken, run_with_token};
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(&parse(&example.input).unwrap()).unwrap()
+        });
+    }
+
+    #[test]
+    fn check_solution_part_two() {
+        check_examples(README, 2, |example| {
+            solve_part_two(&parse(&example.input).unwrap()).unwrap()
+        });
+    }
+
+    #[test]
+    fn check_cancellation() {
+        let machines = parse("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap();
+        let token = CancelToken::new();
+        token.cancel();
+        assert_eq!(
+            run_with_token(&token, || solve_part_one(&machines)),
+            Err(MachineError::Cancelled)
+        );
+        assert_eq!(
+            run_with_token(&token, || solve_part_two(&machines)),
+            Err(MachineError::Cancelled)
+        );
+    }
+
+    #[test]
+    fn check_invalid_machines() {
+        let error = parse("[.##.] (3) (1,3) (2) (2,3 {3,5,4,7}").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (22, "(2,3"));
+
+        let error = parse("[.##.] (3) (1,3) {3,5,4,7}\n[.##.] (3) (1,4) {3,5,4,7}").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 15, "4")
+        );
+
+        let error = parse("[.##.] (3) (1,3) {3,5,4}").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (19, "3,5,4"));
+
+        let lights = ".".repeat(33);
+        let joltages = vec!["0"; 33].join(",");
+        let error = parse(&format!("[{}] (32) {{{}}}", lights, joltages)).unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (2, lights.as_str()));
+        let line = format!(
+            "[#{}] (31) {{{}}}",
+            &lights[2..],
+            joltages.replacen("0,", "", 1)
+        );
+        assert!(parse(&line).is_ok());
+    }
+
+    #[test]
+    fn check_unreachable_machines() {
+        let machines =
+            parse("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n[.#] (0) {0,1}").unwrap();
+        assert_eq!(
+            solve_part_one(&machines),
+            Err(MachineError::Unreachable { machine: 2 })
+        );
+        assert_eq!(
+            solve_part_two(&machines),
+            Err(MachineError::Unreachable { machine: 2 })
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use std::str::FromStr;
+
+use aoc_common::ParseError;
+use aoc_common::error::parse_number;
+
+const MAX_LIGHTS: usize = u32::BITS as usize;
+
+/// Machine from one line of the manual: the expected state of the indicator lights, the lights
+/// or counters every button toggles and the joltage requirements of the counters.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Machine {
+    pub lights: Vec<bool>,
+    pub buttons: Vec<Vec<u32>>,
+    pub joltage_requirements: Vec<u32>,
+}
+
+// Both machines are described by the same line: [.##.] (3) (1,3) (2) {3,5,4,7}
+// The lights are first, the joltage requirements are last and the buttons are in between.
+pub struct MachineParts<'a> {
+    pub lights: &'a str,
+    pub buttons: Vec<&'a str>,
+    pub joltage_requirements: &'a str,
+}
+
+fn strip_delimiters<'a>(
+    line: &str,
+    part: &'a str,
+    open: char,
+    close: char,
+    message: &str,
+) -> Result<&'a str, ParseError> {
+    part.strip_prefix(open)
+        .and_then(|part| part.strip_suffix(close))
+        .ok_or_else(|| ParseError::at_token(line, part, message))
+}
+
+pub fn split_machine_line(line: &str) -> Result<MachineParts<'_>, ParseError> {
+    let mut parts: Vec<&str> = line.split(' ').collect();
+    if parts.len() < 3 {
+        return Err(ParseError::at_token(
+            line,
+            line,
+            "expected lights, buttons and joltage requirements",
+        ));
+    }
+
+    let joltage_requirements = parts.pop().unwrap_or_default();
+    let lights = parts.remove(0);
+
+    Ok(MachineParts {
+        lights: strip_delimiters(line, lights, '[', ']', "expected lights in format [.#]")?,
+        buttons: parts,
+        joltage_requirements: strip_delimiters(
+            line,
+            joltage_requirements,
+            '{',
+            '}',
+            "expected joltage requirements in format {1,2}",
+        )?,
+    })
+}
+
+pub fn parse_lights(line: &str, lights: &str) -> Result<Vec<bool>, ParseError> {
+    lights
+        .char_indices()
+        .map(|(index, character)| match character {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => {
+                let token = &lights[index..index + character.len_utf8()];
+                Err(ParseError::at_token(line, token, "expected # or ."))
+            }
+        })
+        .collect()
+}
+
+// Buttons toggle the lights by their index, so the index has to point to one of the lights
+pub fn parse_button(line: &str, button: &str, light_count: usize) -> Result<Vec<u32>, ParseError> {
+    let values = strip_delimiters(line, button, '(', ')', "expected a button in format (1,2)")?;
+    values
+        .split(',')
+        .map(|value| {
+            let index = parse_number::<u32>(line, value)?;
+            if index as usize >= light_count {
+                return Err(ParseError::at_token(
+                    line,
+                    value,
+                    "expected an index of one of the lights",
+                ));
+            }
+            Ok(index)
+        })
+        .collect()
+}
+
+pub fn parse_joltage_requirements<T: FromStr>(
+    line: &str,
+    joltage_requirements: &str,
+) -> Result<Vec<T>, ParseError> {
+    joltage_requirements
+        .split(',')
+        .map(|requirement| parse_number::<T>(line, requirement))
+        .collect()
+}
+
+/// Parses a single machine like `[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}`. There is one
+/// joltage counter for every light, so the buttons are valid for both parts.
+pub fn parse_machine(line: &str) -> Result<Machine, ParseError> {
+    let parts = split_machine_line(line)?;
+
+    let lights = parse_lights(line, parts.lights)?;
+    // The solvers keep the lights and the buttons as the bits of an u32
+    if lights.len() > MAX_LIGHTS {
+        return Err(ParseError::at_token(
+            line,
+            parts.lights,
+            &format!("expected at most {} lights", MAX_LIGHTS),
+        ));
+    }
+    let joltage_requirements: Vec<u32> =
+        parse_joltage_requirements(line, parts.joltage_requirements)?;
+    if joltage_requirements.len() != lights.len() {
+        return Err(ParseError::at_token(
+            line,
+            parts.joltage_requirements,
+            "expected one joltage requirement for every light",
+        ));
+    }
+
+    let mut buttons: Vec<Vec<u32>> = Vec::new();
+    for part in parts.buttons.iter() {
+        buttons.push(parse_button(line, part, lights.len())?);
+    }
+
+    Ok(Machine {
+        lights,
+        buttons,
+        joltage_requirements,
+    })
+}
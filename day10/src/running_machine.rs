@@ -1,43 +1,23 @@
+use aoc_common::cancel::{self, Cancelled};
+use aoc_common::checked;
+use log::debug;
+
+use crate::parsing::Machine;
+
 pub struct RunningMachine {
     buttons: Vec<Vec<u32>>,
     joltage_requirements: Vec<i32>,
 }
 
 impl RunningMachine {
-    pub fn from_string(line: &String) -> Self {
-        let mut parts: Vec<&str> = line.split(' ').collect();
-        if parts.len() < 3 {
-            panic!("Invalid input string for machine configuration");
-        }
-
-        let mut joltage_requirements_string = parts.pop().unwrap();
-        joltage_requirements_string =
-            &joltage_requirements_string[1..joltage_requirements_string.len() - 1];
-        let joltage_requirements: Vec<i32> = joltage_requirements_string
-            .split(',')
-            .map(|requirement| requirement.parse::<i32>().unwrap())
-            .collect();
-
-        let mut buttons: Vec<Vec<u32>> = Vec::new();
-        for i in 1..parts.len() {
-            let button_values: Vec<u32> = parts[i]
-                .chars()
-                .filter(|character| {
-                    if *character == '(' || *character == ')' || *character == ',' {
-                        false
-                    } else {
-                        true
-                    }
-                })
-                .map(|character| character.to_digit(10).unwrap())
-                .collect();
-
-            buttons.push(button_values);
-        }
-
+    pub fn new(machine: &Machine) -> Self {
         Self {
-            buttons,
-            joltage_requirements,
+            buttons: machine.buttons.clone(),
+            joltage_requirements: machine
+                .joltage_requirements
+                .iter()
+                .map(|&requirement| requirement as i32)
+                .collect(),
         }
     }
 }
@@ -45,37 +25,32 @@ impl RunningMachine {
 fn get_binary_buttons(buttons: &[Vec<u32>]) -> Vec<u32> {
     buttons
         .iter()
-        .map(|b| b.iter().map(|n| 1u32 << n).sum())
+        .map(|b| checked::sum(b.iter().map(|n| 1u32 << n), "bits of the button"))
         .collect()
 }
 
-fn subsets<T: Copy>(set: &[T]) -> Vec<Vec<T>> {
+// Every subset of the set, the enumeration stops as soon as the solver is cancelled
+fn subsets<T: Copy>(set: &[T]) -> Result<Vec<Vec<T>>, Cancelled> {
     let mut subsets: Vec<Vec<T>> = Vec::new();
     for count in 0..=set.len() {
-        subsets.extend(get_combinations(set, count));
+        subsets.extend(get_combinations(set, count)?);
     }
-    subsets
+    Ok(subsets)
 }
 
-fn get_combinations<T: Copy>(set: &[T], count: usize) -> Vec<Vec<T>> {
+fn get_combinations<T: Copy>(set: &[T], count: usize) -> Result<Vec<Vec<T>>, Cancelled> {
+    cancel::check()?;
     if count == 0 {
-        vec![Vec::new()]
-    } else {
-        set[..set.len() - count + 1]
-            .iter()
-            .enumerate()
-            .flat_map(|(i, &t)| {
-                get_combinations(&set[i + 1..], count - 1)
-                    .iter()
-                    .map(|c| {
-                        let mut c1 = c.clone();
-                        c1.push(t);
-                        c1
-                    })
-                    .collect::<Vec<Vec<T>>>()
-            })
-            .collect()
+        return Ok(vec![Vec::new()]);
+    }
+    let mut combinations = Vec::new();
+    for (i, &t) in set[..set.len() - count + 1].iter().enumerate() {
+        for mut combination in get_combinations(&set[i + 1..], count - 1)? {
+            combination.push(t);
+            combinations.push(combination);
+        }
     }
+    Ok(combinations)
 }
 
 // Tranforms the list of joltages into a list of booleans
@@ -85,18 +60,21 @@ fn get_joltages_as_even_odd_integer(joltages: &[i32]) -> u32 {
     joltages
         .iter()
         .enumerate()
-        .map(|(i, j)| ((1 << i) * (j % 2)) as u32)
+        .map(|(i, j)| ((j % 2) as u32) << i)
         .sum()
 }
 
 fn solve_running_machine_recursive(
     subset_xors: &[(Vec<u32>, u32)],
     joltages: &[i32],
-) -> Option<usize> {
+) -> Result<Option<usize>, Cancelled> {
+    // The runner gave up on the machine, there is no point in looking any further
+    cancel::check()?;
+
     // If all joltages are 0 then there are no button presses left
     // end recursion
     if joltages.iter().all(|&j| j == 0) {
-        return Some(0);
+        return Ok(Some(0));
     }
 
     // Convert joltages to binary value based on whether they are even or odd
@@ -104,7 +82,7 @@ fn solve_running_machine_recursive(
 
     let mut solution = None;
     for (subset, xor) in subset_xors {
-        if !(*xor == joltages_as_even_odd_integer) {
+        if *xor != joltages_as_even_odd_integer {
             continue;
         }
 
@@ -117,27 +95,31 @@ fn solve_running_machine_recursive(
         }
 
         if new_joltages.iter().all(|&j| j >= 0) {
-            let press_count = solve_running_machine_recursive(subset_xors, &new_joltages)
+            let press_count = solve_running_machine_recursive(subset_xors, &new_joltages)?
                 .map(|c| subset.len() + 2 * c);
 
             solution = solution.min(press_count).or(solution).or(press_count);
         }
     }
-    solution
+    Ok(solution)
 }
 
 // I have not come up with this solution. I was going for gaussian elimination, but got stuck on handling
 // fractions. After a while I gave up. Later I finished the rest of the problems I went to reddit and saw this
 // solution here: https://www.reddit.com/r/adventofcode/comments/1pk87hl/2025_day_10_part_2_bifurcate_your_way_to_victory/
 // So this is implementation of that solution.
-pub fn solve_running_machine(machine: &RunningMachine, index: usize) -> usize {
-    println!("Solving machine at index {}", index);
+// None when no presses of the buttons reach the joltage requirements
+pub fn solve_running_machine(
+    machine: &RunningMachine,
+    index: usize,
+) -> Result<Option<usize>, Cancelled> {
+    debug!("Solving machine at index {}", index);
 
     let binary_buttons = get_binary_buttons(&machine.buttons);
-    let subset_xors: Vec<_> = subsets(&binary_buttons)
+    let subset_xors: Vec<_> = subsets(&binary_buttons)?
         .iter()
         .map(|subset| (subset.clone(), subset.iter().fold(0, |a, &b| a ^ b)))
         .collect();
 
-    solve_running_machine_recursive(&subset_xors, &machine.joltage_requirements).unwrap()
+    solve_running_machine_recursive(&subset_xors, &machine.joltage_requirements)
 }
